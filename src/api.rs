@@ -0,0 +1,429 @@
+use std::error::Error;
+use std::thread;
+use std::time::Duration;
+
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+
+use crate::attaques::{AttaqueApprise, nom_attaque};
+use crate::cache::CacheHttp;
+use crate::config::ConfigApi;
+use crate::croissance::CourbeCroissance;
+use crate::especes::{Espece, SpeciesSource, capitaliser};
+use crate::evolution::{Declencheur, Evolution};
+use crate::groupe_oeuf::GroupeOeuf;
+use crate::pokemon::{RepartitionGenres, TypePokemon};
+use crate::stats::{Statistique, Stats};
+use crate::talent::Talent;
+
+// Structures pour désérialiser la réponse de l'API
+#[derive(Deserialize, Debug)]
+pub struct PokemonApiResponse {
+    pub name: String,
+    pub types: Vec<TypeWrapper>,
+    #[serde(default)]
+    pub stats: Vec<StatWrapper>,
+    #[serde(default)]
+    pub moves: Vec<MoveWrapper>,
+    #[serde(default)]
+    pub abilities: Vec<AbilityWrapper>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TypeWrapper {
+    #[serde(rename = "type")]
+    pub type_info: TypeInfo,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TypeInfo {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StatWrapper {
+    pub base_stat: u32,
+    pub stat: StatInfo,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StatInfo {
+    pub name: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AbilityWrapper {
+    pub ability: NamedResource,
+    #[serde(default)]
+    pub is_hidden: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MoveWrapper {
+    #[serde(rename = "move")]
+    pub move_info: NamedResource,
+    #[serde(default)]
+    pub version_group_details: Vec<VersionGroupDetail>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct VersionGroupDetail {
+    pub level_learned_at: u32,
+    pub move_learn_method: NamedResource,
+    pub version_group: NamedResource,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PokemonSpeciesResponse {
+    pub growth_rate: NamedResource,
+    #[serde(default)]
+    pub evolution_chain: Option<UrlResource>,
+    #[serde(default)]
+    pub egg_groups: Vec<NamedResource>,
+    #[serde(default)]
+    pub hatch_counter: Option<u32>,
+    #[serde(default = "taux_femelles_par_defaut")]
+    pub gender_rate: i32,
+}
+
+fn taux_femelles_par_defaut() -> i32 {
+    RepartitionGenres::default().taux()
+}
+
+// Référence nommée renvoyée par l'API ({ "name": ..., "url": ... })
+#[derive(Deserialize, Debug)]
+pub struct NamedResource {
+    pub name: String,
+    #[serde(default)]
+    pub url: String,
+}
+
+// Référence non nommée ({ "url": ... })
+#[derive(Deserialize, Debug)]
+pub struct UrlResource {
+    pub url: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EvolutionChainResponse {
+    pub chain: ChainLink,
+}
+
+// Maillon d'une chaîne d'évolution: une espèce et ses évolutions directes
+#[derive(Deserialize, Debug)]
+pub struct ChainLink {
+    pub species: NamedResource,
+    #[serde(default)]
+    pub evolution_details: Vec<EvolutionDetail>,
+    #[serde(default)]
+    pub evolves_to: Vec<ChainLink>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EvolutionDetail {
+    pub trigger: NamedResource,
+    #[serde(default)]
+    pub min_level: Option<u32>,
+    #[serde(default)]
+    pub item: Option<NamedResource>,
+    #[serde(default)]
+    pub min_happiness: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PokemonListResponse {
+    pub results: Vec<PokemonListItem>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PokemonListItem {
+    pub name: String,
+    pub url: String,
+}
+
+// Conversion des types de la PokeAPI vers notre énumération
+pub fn convert_type_from_api(api_type: &str) -> TypePokemon {
+    match api_type.to_lowercase().as_str() {
+        "fire" => TypePokemon::Feu,
+        "water" => TypePokemon::Eau,
+        "grass" => TypePokemon::Plante,
+        "electric" => TypePokemon::Electrik,
+        "normal" => TypePokemon::Normal,
+        "psychic" => TypePokemon::Psy,
+        "poison" => TypePokemon::Poison,
+        "ground" => TypePokemon::Sol,
+        "flying" => TypePokemon::Vol,
+        "fighting" => TypePokemon::Combat,
+        "rock" => TypePokemon::Roche,
+        "bug" => TypePokemon::Insecte,
+        "ghost" => TypePokemon::Spectre,
+        "ice" => TypePokemon::Glace,
+        "dragon" => TypePokemon::Dragon,
+        "fairy" => TypePokemon::Fee,
+        "steel" => TypePokemon::Acier,
+        "dark" => TypePokemon::Tenebres,
+        _ => TypePokemon::Normal, // Par défaut
+    }
+}
+
+// Conversion des noms de statistiques de la PokeAPI
+pub fn convert_stat_from_api(api_stat: &str) -> Option<Statistique> {
+    match api_stat {
+        "hp" => Some(Statistique::Pv),
+        "attack" => Some(Statistique::Attaque),
+        "defense" => Some(Statistique::Defense),
+        "special-attack" => Some(Statistique::AttaqueSpe),
+        "special-defense" => Some(Statistique::DefenseSpe),
+        "speed" => Some(Statistique::Vitesse),
+        _ => None,
+    }
+}
+
+// Conversion des courbes d'expérience de la PokeAPI
+pub fn convert_croissance_from_api(api_croissance: &str) -> Option<CourbeCroissance> {
+    match api_croissance {
+        "fast" => Some(CourbeCroissance::Rapide),
+        "medium" => Some(CourbeCroissance::MoyenneRapide),
+        "medium-slow" => Some(CourbeCroissance::MoyenneLente),
+        "slow" => Some(CourbeCroissance::Lente),
+        "slow-then-very-fast" => Some(CourbeCroissance::Erratique),
+        "fast-then-very-slow" => Some(CourbeCroissance::Fluctuante),
+        _ => None,
+    }
+}
+
+// Conversion des groupes Œuf de la PokeAPI
+pub fn convert_groupe_oeuf_from_api(api_groupe: &str) -> Option<GroupeOeuf> {
+    match api_groupe {
+        "monster" => Some(GroupeOeuf::Monstre),
+        "water1" => Some(GroupeOeuf::Eau1),
+        "bug" => Some(GroupeOeuf::Insecte),
+        "flying" => Some(GroupeOeuf::Vol),
+        "ground" => Some(GroupeOeuf::Terrestre),
+        "fairy" => Some(GroupeOeuf::Fee),
+        "plant" => Some(GroupeOeuf::Plante),
+        "humanshape" => Some(GroupeOeuf::Humanoide),
+        "water3" => Some(GroupeOeuf::Eau3),
+        "mineral" => Some(GroupeOeuf::Mineral),
+        "indeterminate" => Some(GroupeOeuf::Amorphe),
+        "water2" => Some(GroupeOeuf::Eau2),
+        "ditto" => Some(GroupeOeuf::Metamorph),
+        "dragon" => Some(GroupeOeuf::Dragon),
+        "no-eggs" => Some(GroupeOeuf::Inconnu),
+        _ => None,
+    }
+}
+
+// Conversion des conditions d'évolution de la PokeAPI
+pub fn convert_declencheur_from_api(detail: &EvolutionDetail) -> Declencheur {
+    match (detail.trigger.name.as_str(), detail.min_level, &detail.item) {
+        ("level-up", Some(niveau), _) => Declencheur::Niveau(niveau),
+        ("level-up", None, _) if detail.min_happiness.is_some() => Declencheur::Bonheur,
+        ("use-item", _, Some(objet)) => Declencheur::Pierre(objet.name.clone()),
+        ("trade", _, _) => Declencheur::Echange,
+        _ => Declencheur::Autre,
+    }
+}
+
+// Numéro en fin d'URL de la PokeAPI (".../pokemon-species/25/" -> 25)
+fn id_depuis_url(url: &str) -> Option<u32> {
+    url.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+// Évolutions directes d'une espèce dans une chaîne d'évolution
+fn evolutions_dans_chaine(maillon: &ChainLink, id: u32) -> Option<Vec<Evolution>> {
+    if id_depuis_url(&maillon.species.url) == Some(id) {
+        let evolutions = maillon
+            .evolves_to
+            .iter()
+            .filter_map(|suivant| {
+                Some(Evolution {
+                    espece_id: id_depuis_url(&suivant.species.url)?,
+                    nom_espece: capitaliser(&suivant.species.name),
+                    declencheur: suivant
+                        .evolution_details
+                        .first()
+                        .map(convert_declencheur_from_api)
+                        .unwrap_or(Declencheur::Autre),
+                })
+            })
+            .collect();
+        return Some(evolutions);
+    }
+    maillon
+        .evolves_to
+        .iter()
+        .find_map(|suivant| evolutions_dans_chaine(suivant, id))
+}
+
+// Source en ligne interrogeant la PokeAPI
+pub struct PokeApi {
+    client: Client,
+    config: ConfigApi,
+    cache: Option<CacheHttp>,
+}
+
+impl Default for PokeApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PokeApi {
+    pub fn new() -> Self {
+        Self::avec_config(ConfigApi::default())
+    }
+
+    // Source utilisant une URL de base, un délai, des tentatives et un user agent donnés
+    pub fn avec_config(config: ConfigApi) -> Self {
+        let client = Client::builder()
+            .timeout(config.delai)
+            .user_agent(config.user_agent.clone())
+            .build()
+            .unwrap_or_default();
+
+        PokeApi {
+            client,
+            config,
+            cache: None,
+        }
+    }
+
+    // Conserver les réponses dans un cache disque
+    pub fn avec_cache(mut self, cache: CacheHttp) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    // Récupérer et désérialiser une réponse, en passant par le cache s'il existe
+    fn get_json<T: DeserializeOwned>(&self, chemin: &str) -> Result<T, Box<dyn Error>> {
+        let url = format!("{}/{}", self.config.url_base, chemin);
+        match &self.cache {
+            Some(cache) => cache.get_json(&self.client, &url, self.config.tentatives),
+            None => Ok(envoyer(self.client.get(&url), self.config.tentatives)?
+                .error_for_status()?
+                .json()?),
+        }
+    }
+}
+
+// Envoyer une requête, en la retentant sur les erreurs réseau et les erreurs serveur (5xx)
+pub(crate) fn envoyer(requete: RequestBuilder, tentatives: u32) -> reqwest::Result<Response> {
+    let mut essai = 0;
+    loop {
+        let Some(copie) = requete.try_clone() else {
+            return requete.send();
+        };
+        let peut_reessayer = essai < tentatives;
+        match copie.send() {
+            Ok(response) if response.status().is_server_error() && peut_reessayer => {}
+            Err(e) if (e.is_timeout() || e.is_connect()) && peut_reessayer => {}
+            resultat => return resultat,
+        }
+        essai += 1;
+        thread::sleep(Duration::from_millis(100 * u64::from(essai)));
+    }
+}
+
+impl SpeciesSource for PokeApi {
+    // Récupérer une espèce depuis l'API
+    fn espece(&self, id: u32) -> Result<Espece, Box<dyn Error>> {
+        let pokemon_data: PokemonApiResponse = self.get_json(&format!("pokemon/{}", id))?;
+        let species_data: PokemonSpeciesResponse =
+            self.get_json(&format!("pokemon-species/{}", id))?;
+
+        // La chaîne d'évolution est demandée à l'URL de base configurée, pas à l'URL renvoyée
+        let mut evolutions = Vec::new();
+        if let Some(chaine_id) = species_data
+            .evolution_chain
+            .as_ref()
+            .and_then(|chaine| id_depuis_url(&chaine.url))
+        {
+            let chaine: EvolutionChainResponse =
+                self.get_json(&format!("evolution-chain/{}", chaine_id))?;
+            evolutions = evolutions_dans_chaine(&chaine.chain, id).unwrap_or_default();
+        }
+
+        let mut stats_de_base = Stats::default();
+        for wrapper in &pokemon_data.stats {
+            if let Some(stat) = convert_stat_from_api(&wrapper.stat.name) {
+                stats_de_base.definir(stat, wrapper.base_stat);
+            }
+        }
+
+        // Attaques apprises par niveau dans le groupe de versions configuré
+        let mut apprentissage: Vec<AttaqueApprise> = pokemon_data
+            .moves
+            .iter()
+            .flat_map(|wrapper| {
+                wrapper
+                    .version_group_details
+                    .iter()
+                    .filter(|detail| {
+                        detail.move_learn_method.name == "level-up"
+                            && detail.version_group.name == self.config.groupe_versions
+                    })
+                    .map(|detail| AttaqueApprise {
+                        niveau: detail.level_learned_at.max(1),
+                        nom: nom_attaque(&wrapper.move_info.name),
+                    })
+            })
+            .collect();
+        apprentissage.sort_by_key(|attaque| attaque.niveau);
+
+        // Attaques Œuf dans le groupe de versions configuré pour la reproduction
+        let attaques_oeuf = pokemon_data
+            .moves
+            .iter()
+            .filter(|wrapper| {
+                wrapper.version_group_details.iter().any(|detail| {
+                    detail.move_learn_method.name == "egg"
+                        && detail.version_group.name == self.config.groupe_versions_oeuf
+                })
+            })
+            .map(|wrapper| nom_attaque(&wrapper.move_info.name))
+            .collect();
+
+        Ok(Espece {
+            id,
+            nom: capitaliser(&pokemon_data.name),
+            types: pokemon_data
+                .types
+                .iter()
+                .map(|wrapper| convert_type_from_api(&wrapper.type_info.name))
+                .collect(),
+            stats_de_base,
+            croissance: convert_croissance_from_api(&species_data.growth_rate.name)
+                .unwrap_or_default(),
+            evolutions,
+            groupes_oeuf: species_data
+                .egg_groups
+                .iter()
+                .filter_map(|groupe| convert_groupe_oeuf_from_api(&groupe.name))
+                .collect(),
+            cycles_eclosion: species_data.hatch_counter,
+            repartition_genres: RepartitionGenres::depuis_taux(species_data.gender_rate),
+            apprentissage,
+            attaques_oeuf,
+            talents: pokemon_data
+                .abilities
+                .iter()
+                .map(|wrapper| Talent::depuis_api(&wrapper.ability.name, wrapper.is_hidden))
+                .collect(),
+        })
+    }
+
+    // Liste des 151 premiers Pokémon
+    fn liste_especes(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        let pokemon_list: PokemonListResponse = self.get_json("pokemon?limit=151")?;
+
+        let noms = pokemon_list
+            .results
+            .into_iter()
+            .map(|item| capitaliser(&item.name))
+            .collect();
+
+        Ok(noms)
+    }
+}
@@ -0,0 +1,96 @@
+use crate::elevage::Elevage;
+
+// Nombre maximal d'attaques connues par un Pokémon
+pub const ATTAQUES_MAX: usize = 4;
+
+// Attaque apprise par une espèce en montant de niveau
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttaqueApprise {
+    pub niveau: u32,
+    pub nom: String,
+}
+
+// Nom d'attaque lisible à partir du nom PokéAPI ("leech-seed" -> "Leech Seed")
+pub fn nom_attaque(nom_api: &str) -> String {
+    nom_api
+        .split('-')
+        .map(|mot| {
+            let mut lettres = mot.chars();
+            match lettres.next() {
+                Some(premiere) => premiere.to_uppercase().collect::<String>() + lettres.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Attaques les plus récentes qu'un Pokémon connaît en arrivant à un niveau
+pub fn attaques_au_niveau(apprentissage: &[AttaqueApprise], niveau: u32) -> Vec<String> {
+    let mut attaques: Vec<String> = Vec::new();
+    for attaque in apprentissage
+        .iter()
+        .filter(|attaque| attaque.niveau <= niveau)
+    {
+        if !attaques.contains(&attaque.nom) {
+            attaques.push(attaque.nom.clone());
+        }
+    }
+    let surplus = attaques.len().saturating_sub(ATTAQUES_MAX);
+    attaques.split_off(surplus)
+}
+
+// Apprentissage au format "1:Tackle,7:Leech Seed"
+pub fn apprentissage_en_texte(apprentissage: &[AttaqueApprise]) -> String {
+    apprentissage
+        .iter()
+        .map(|attaque| format!("{}:{}", attaque.niveau, attaque.nom))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn apprentissage_depuis_texte(texte: &str) -> Vec<AttaqueApprise> {
+    texte
+        .split(',')
+        .filter_map(|attaque| {
+            let (niveau, nom) = attaque.split_once(':')?;
+            Some(AttaqueApprise {
+                niveau: niveau.parse().ok()?,
+                nom: nom.to_string(),
+            })
+        })
+        .collect()
+}
+
+impl Elevage {
+    // Attaques que des Pokémon veulent apprendre alors qu'ils en connaissent déjà quatre
+    pub fn attaques_en_attente(&self) -> Vec<(u32, String)> {
+        self.pokemon
+            .iter()
+            .flat_map(|pokemon| {
+                pokemon
+                    .attaques_en_attente
+                    .iter()
+                    .map(|attaque| (pokemon.id, attaque.clone()))
+            })
+            .collect()
+    }
+
+    // Apprendre une attaque en attente en oubliant l'attaque n° `oubliee` (None pour y renoncer)
+    pub fn apprendre_attaque(&mut self, id: u32, attaque: &str, oubliee: Option<usize>) {
+        if let Some(pokemon) = self.pokemon_par_id_mut(id) {
+            pokemon.attaques_en_attente.retain(|a| a != attaque);
+            match oubliee {
+                Some(index) if index < pokemon.attaques.len() => {
+                    let ancienne =
+                        std::mem::replace(&mut pokemon.attaques[index], attaque.to_string());
+                    println!(
+                        "1, 2, 3... Tada! {} a oublié {} et appris {}!",
+                        pokemon.nom, ancienne, attaque
+                    );
+                }
+                _ => println!("{} n'a pas appris {}.", pokemon.nom, attaque),
+            }
+        }
+    }
+}
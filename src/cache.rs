@@ -0,0 +1,241 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::StatusCode;
+use reqwest::blocking::Client;
+use reqwest::header::{CACHE_CONTROL, ETAG, IF_NONE_MATCH};
+use serde::de::DeserializeOwned;
+
+use crate::api::envoyer;
+
+// Répertoire par défaut du cache (modifiable via ELEVAGE_CACHE_DIR)
+const REPERTOIRE_PAR_DEFAUT: &str = "cache_pokeapi";
+
+// Durée de validité utilisée quand le serveur n'indique pas de max-age
+const DUREE_PAR_DEFAUT: Duration = Duration::from_secs(24 * 60 * 60);
+
+// Métadonnées associées à une réponse en cache
+#[derive(Debug, Clone, Default)]
+struct Metadonnees {
+    url: String,
+    etag: Option<String>,
+    expiration: u64,
+}
+
+impl Metadonnees {
+    fn lire(contenu: &str) -> Self {
+        let mut meta = Metadonnees::default();
+        for line in contenu.lines() {
+            if let Some((cle, valeur)) = line.split_once('=') {
+                match cle {
+                    "url" => meta.url = valeur.to_string(),
+                    "etag" => meta.etag = Some(valeur.to_string()),
+                    "expiration" => meta.expiration = valeur.parse().unwrap_or(0),
+                    _ => {}
+                }
+            }
+        }
+        meta
+    }
+
+    fn ecrire(&self) -> String {
+        let mut contenu = format!("url={}\nexpiration={}\n", self.url, self.expiration);
+        if let Some(etag) = &self.etag {
+            contenu.push_str(&format!("etag={}\n", etag));
+        }
+        contenu
+    }
+}
+
+// Cache disque des réponses HTTP, indexé par URL
+#[derive(Debug, Clone)]
+pub struct CacheHttp {
+    repertoire: PathBuf,
+}
+
+impl Default for CacheHttp {
+    fn default() -> Self {
+        Self::par_defaut()
+    }
+}
+
+impl CacheHttp {
+    pub fn new(repertoire: impl Into<PathBuf>) -> Self {
+        CacheHttp {
+            repertoire: repertoire.into(),
+        }
+    }
+
+    // Cache situé dans ELEVAGE_CACHE_DIR, ou dans le répertoire par défaut
+    pub fn par_defaut() -> Self {
+        Self::new(env::var("ELEVAGE_CACHE_DIR").unwrap_or_else(|_| REPERTOIRE_PAR_DEFAUT.into()))
+    }
+
+    // Récupérer une réponse JSON, depuis le cache si elle est encore valide
+    pub fn get_json<T: DeserializeOwned>(
+        &self,
+        client: &Client,
+        url: &str,
+        tentatives: u32,
+    ) -> Result<T, Box<dyn Error>> {
+        let corps = self.get(client, url, tentatives)?;
+        Ok(serde_json::from_str(&corps)?)
+    }
+
+    // Récupérer le corps d'une réponse, depuis le cache si possible
+    pub fn get(
+        &self,
+        client: &Client,
+        url: &str,
+        tentatives: u32,
+    ) -> Result<String, Box<dyn Error>> {
+        let (chemin_corps, chemin_meta) = self.chemins(url);
+        let meta = fs::read_to_string(&chemin_meta)
+            .map(|contenu| Metadonnees::lire(&contenu))
+            .unwrap_or_default();
+        // Plusieurs URL peuvent partager les mêmes fichiers: l'entrée ne sert que l'URL enregistrée
+        let en_cache = if meta.url == url {
+            fs::read_to_string(&chemin_corps).ok()
+        } else {
+            None
+        };
+
+        // Réponse encore valide: pas besoin de contacter le serveur
+        if let Some(corps) = &en_cache
+            && meta.expiration > maintenant()
+        {
+            return Ok(corps.clone());
+        }
+
+        // Revalider avec l'ETag si on en a un
+        let mut requete = client.get(url);
+        if let (Some(_), Some(etag)) = (&en_cache, &meta.etag) {
+            requete = requete.header(IF_NONE_MATCH, etag);
+        }
+
+        let response = match envoyer(requete, tentatives) {
+            Ok(response) => response,
+            // Hors-ligne: on préfère une réponse périmée à une erreur
+            Err(e) => return en_cache.ok_or_else(|| e.into()),
+        };
+
+        // Serveur toujours en erreur après les tentatives: même repli que hors-ligne
+        if response.status().is_server_error()
+            && let Some(corps) = en_cache
+        {
+            return Ok(corps);
+        }
+
+        let expiration = maintenant() + duree_validite(&response).as_secs();
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(corps) = en_cache
+        {
+            self.enregistrer_meta(url, meta.etag, expiration)?;
+            return Ok(corps);
+        }
+
+        let response = response.error_for_status()?;
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|valeur| valeur.to_str().ok())
+            .map(|valeur| valeur.to_string());
+        let corps = response.text()?;
+
+        fs::create_dir_all(&self.repertoire)?;
+        fs::write(&chemin_corps, &corps)?;
+        self.enregistrer_meta(url, etag, expiration)?;
+
+        Ok(corps)
+    }
+
+    // Supprimer toutes les réponses en cache, renvoie le nombre d'entrées supprimées.
+    // Seules les paires .json/.meta écrites par le cache sont supprimées: le répertoire peut
+    // contenir d'autres fichiers (ELEVAGE_CACHE_DIR=. par exemple)
+    pub fn vider(&self) -> io::Result<usize> {
+        let entrees = match fs::read_dir(&self.repertoire) {
+            Ok(entrees) => entrees,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        let mut supprimees = 0;
+        for entree in entrees {
+            let chemin_meta = entree?.path();
+            if !chemin_meta.is_file() || chemin_meta.extension().is_none_or(|ext| ext != "meta") {
+                continue;
+            }
+            // Le fichier de métadonnées doit désigner une URL dont il est bien le fichier
+            let Ok(contenu) = fs::read_to_string(&chemin_meta) else {
+                continue;
+            };
+            let meta = Metadonnees::lire(&contenu);
+            if meta.url.is_empty() {
+                continue;
+            }
+            let (chemin_corps, attendu) = self.chemins(&meta.url);
+            if attendu != chemin_meta {
+                continue;
+            }
+
+            if chemin_corps.is_file() {
+                fs::remove_file(chemin_corps)?;
+                supprimees += 1;
+            }
+            fs::remove_file(chemin_meta)?;
+        }
+        Ok(supprimees)
+    }
+
+    fn enregistrer_meta(&self, url: &str, etag: Option<String>, expiration: u64) -> io::Result<()> {
+        let meta = Metadonnees {
+            url: url.to_string(),
+            etag,
+            expiration,
+        };
+        fs::create_dir_all(&self.repertoire)?;
+        fs::write(self.chemins(url).1, meta.ecrire())
+    }
+
+    // Fichiers du corps et des métadonnées pour une URL (la clé n'est pas unique: voir `get`)
+    fn chemins(&self, url: &str) -> (PathBuf, PathBuf) {
+        let cle: String = url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        (
+            self.repertoire.join(format!("{}.json", cle)),
+            self.repertoire.join(format!("{}.meta", cle)),
+        )
+    }
+}
+
+// Durée de validité annoncée par le serveur (Cache-Control: max-age)
+fn duree_validite(response: &reqwest::blocking::Response) -> Duration {
+    response
+        .headers()
+        .get(CACHE_CONTROL)
+        .and_then(|valeur| valeur.to_str().ok())
+        .and_then(|valeur| {
+            valeur
+                .split(',')
+                .filter_map(|directive| directive.trim().strip_prefix("max-age="))
+                .find_map(|secondes| secondes.parse().ok())
+        })
+        .map(Duration::from_secs)
+        .unwrap_or(DUREE_PAR_DEFAUT)
+}
+
+fn maintenant() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duree| duree.as_secs())
+        .unwrap_or(0)
+}
@@ -0,0 +1,99 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+// Fichier de configuration par défaut (modifiable via ELEVAGE_CONFIG)
+const FICHIER_PAR_DEFAUT: &str = "elevage.conf";
+
+// Paramètres d'accès à la PokeAPI (ou à un miroir local)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigApi {
+    pub url_base: String,
+    pub delai: Duration,
+    pub tentatives: u32,
+    pub user_agent: String,
+    // Groupe de versions dont on lit les attaques apprises par niveau
+    pub groupe_versions: String,
+    // Groupe de versions dont on lit les attaques Œuf (la reproduction n'existe pas dans Rouge/Bleu)
+    pub groupe_versions_oeuf: String,
+}
+
+impl Default for ConfigApi {
+    fn default() -> Self {
+        ConfigApi {
+            url_base: "https://pokeapi.co/api/v2".to_string(),
+            delai: Duration::from_secs(10),
+            tentatives: 2,
+            user_agent: format!("elevage-pokemon/{}", env!("CARGO_PKG_VERSION")),
+            groupe_versions: "red-blue".to_string(),
+            groupe_versions_oeuf: "gold-silver".to_string(),
+        }
+    }
+}
+
+impl ConfigApi {
+    // Configuration du fichier ELEVAGE_CONFIG (ou elevage.conf), surchargée par les variables d'environnement
+    pub fn charger() -> Self {
+        let fichier = env::var("ELEVAGE_CONFIG").unwrap_or_else(|_| FICHIER_PAR_DEFAUT.into());
+        let mut config = Self::depuis_fichier(&fichier).unwrap_or_default();
+        config.appliquer_env();
+        config
+    }
+
+    // Lire un fichier de configuration au format `cle=valeur`
+    pub fn depuis_fichier(fichier: impl AsRef<Path>) -> Result<Self, io::Error> {
+        let contenu = fs::read_to_string(fichier)?;
+        let mut config = ConfigApi::default();
+        for line in contenu.lines() {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            if let Some((cle, valeur)) = line.split_once('=') {
+                config.definir(cle.trim(), valeur.trim());
+            }
+        }
+        Ok(config)
+    }
+
+    // Surcharger avec ELEVAGE_API_URL, ELEVAGE_API_DELAI, ELEVAGE_API_TENTATIVES, ELEVAGE_API_USER_AGENT
+    // ELEVAGE_API_GROUPE_VERSIONS et ELEVAGE_API_GROUPE_VERSIONS_OEUF
+    pub fn appliquer_env(&mut self) {
+        let variables = [
+            ("ELEVAGE_API_URL", "url_base"),
+            ("ELEVAGE_API_DELAI", "delai"),
+            ("ELEVAGE_API_TENTATIVES", "tentatives"),
+            ("ELEVAGE_API_USER_AGENT", "user_agent"),
+            ("ELEVAGE_API_GROUPE_VERSIONS", "groupe_versions"),
+            ("ELEVAGE_API_GROUPE_VERSIONS_OEUF", "groupe_versions_oeuf"),
+        ];
+        for (variable, cle) in variables {
+            if let Ok(valeur) = env::var(variable) {
+                self.definir(cle, &valeur);
+            }
+        }
+    }
+
+    // Les valeurs invalides sont ignorées et la valeur actuelle conservée
+    fn definir(&mut self, cle: &str, valeur: &str) {
+        match cle {
+            "url_base" => self.url_base = valeur.trim_end_matches('/').to_string(),
+            "delai" => {
+                if let Ok(secondes) = valeur.parse() {
+                    self.delai = Duration::from_secs(secondes);
+                }
+            }
+            "tentatives" => {
+                if let Ok(tentatives) = valeur.parse() {
+                    self.tentatives = tentatives;
+                }
+            }
+            "user_agent" => self.user_agent = valeur.to_string(),
+            "groupe_versions" => self.groupe_versions = valeur.to_string(),
+            "groupe_versions_oeuf" => self.groupe_versions_oeuf = valeur.to_string(),
+            _ => {}
+        }
+    }
+}
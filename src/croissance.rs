@@ -0,0 +1,82 @@
+use std::fmt;
+
+// Niveau maximal d'un Pokémon
+pub const NIVEAU_MAX: u32 = 100;
+
+// Les six courbes d'expérience officielles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CourbeCroissance {
+    Rapide,
+    #[default]
+    MoyenneRapide,
+    MoyenneLente,
+    Lente,
+    Erratique,
+    Fluctuante,
+}
+
+impl CourbeCroissance {
+    pub const TOUTES: [CourbeCroissance; 6] = [
+        CourbeCroissance::Rapide,
+        CourbeCroissance::MoyenneRapide,
+        CourbeCroissance::MoyenneLente,
+        CourbeCroissance::Lente,
+        CourbeCroissance::Erratique,
+        CourbeCroissance::Fluctuante,
+    ];
+
+    // Expérience totale nécessaire pour atteindre un niveau
+    pub fn experience_pour_niveau(&self, niveau: u32) -> u32 {
+        let n = i64::from(niveau.clamp(1, NIVEAU_MAX));
+        if n == 1 {
+            return 0;
+        }
+        let cube = n * n * n;
+        let experience = match self {
+            CourbeCroissance::Rapide => 4 * cube / 5,
+            CourbeCroissance::MoyenneRapide => cube,
+            CourbeCroissance::MoyenneLente => 6 * cube / 5 - 15 * n * n + 100 * n - 140,
+            CourbeCroissance::Lente => 5 * cube / 4,
+            CourbeCroissance::Erratique => match n {
+                ..50 => cube * (100 - n) / 50,
+                50..68 => cube * (150 - n) / 100,
+                68..98 => cube * ((1911 - 10 * n) / 3) / 500,
+                _ => cube * (160 - n) / 100,
+            },
+            CourbeCroissance::Fluctuante => match n {
+                ..15 => cube * ((n + 1) / 3 + 24) / 50,
+                15..36 => cube * (n + 14) / 50,
+                _ => cube * (n / 2 + 32) / 50,
+            },
+        };
+        experience.max(0) as u32
+    }
+
+    // Niveau atteint avec une expérience totale donnée
+    pub fn niveau_pour_experience(&self, experience: u32) -> u32 {
+        (1..=NIVEAU_MAX)
+            .take_while(|niveau| self.experience_pour_niveau(*niveau) <= experience)
+            .last()
+            .unwrap_or(1)
+    }
+
+    // Retrouver une courbe à partir de son nom affiché
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        Self::TOUTES
+            .into_iter()
+            .find(|courbe| courbe.to_string() == nom)
+    }
+}
+
+impl fmt::Display for CourbeCroissance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CourbeCroissance::Rapide => write!(f, "Rapide"),
+            CourbeCroissance::MoyenneRapide => write!(f, "Moyenne-Rapide"),
+            CourbeCroissance::MoyenneLente => write!(f, "Moyenne-Lente"),
+            CourbeCroissance::Lente => write!(f, "Lente"),
+            CourbeCroissance::Erratique => write!(f, "Erratique"),
+            CourbeCroissance::Fluctuante => write!(f, "Fluctuante"),
+        }
+    }
+}
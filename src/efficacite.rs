@@ -0,0 +1,234 @@
+use crate::pokemon::{Pokemon, TypePokemon};
+
+// Types contre lesquels les attaques d'un type sont super efficaces, peu efficaces ou sans effet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Relations {
+    pub super_efficace: &'static [TypePokemon],
+    pub peu_efficace: &'static [TypePokemon],
+    pub sans_effet: &'static [TypePokemon],
+}
+
+// Table des types (depuis la 6e génération)
+pub fn relations(attaque: &TypePokemon) -> Relations {
+    match attaque {
+        TypePokemon::Normal => Relations {
+            super_efficace: &[],
+            peu_efficace: &[TypePokemon::Roche, TypePokemon::Acier],
+            sans_effet: &[TypePokemon::Spectre],
+        },
+        TypePokemon::Feu => Relations {
+            super_efficace: &[
+                TypePokemon::Plante,
+                TypePokemon::Glace,
+                TypePokemon::Insecte,
+                TypePokemon::Acier,
+            ],
+            peu_efficace: &[
+                TypePokemon::Feu,
+                TypePokemon::Eau,
+                TypePokemon::Roche,
+                TypePokemon::Dragon,
+            ],
+            sans_effet: &[],
+        },
+        TypePokemon::Eau => Relations {
+            super_efficace: &[TypePokemon::Feu, TypePokemon::Sol, TypePokemon::Roche],
+            peu_efficace: &[TypePokemon::Eau, TypePokemon::Plante, TypePokemon::Dragon],
+            sans_effet: &[],
+        },
+        TypePokemon::Electrik => Relations {
+            super_efficace: &[TypePokemon::Eau, TypePokemon::Vol],
+            peu_efficace: &[
+                TypePokemon::Electrik,
+                TypePokemon::Plante,
+                TypePokemon::Dragon,
+            ],
+            sans_effet: &[TypePokemon::Sol],
+        },
+        TypePokemon::Plante => Relations {
+            super_efficace: &[TypePokemon::Eau, TypePokemon::Sol, TypePokemon::Roche],
+            peu_efficace: &[
+                TypePokemon::Feu,
+                TypePokemon::Plante,
+                TypePokemon::Poison,
+                TypePokemon::Vol,
+                TypePokemon::Insecte,
+                TypePokemon::Dragon,
+                TypePokemon::Acier,
+            ],
+            sans_effet: &[],
+        },
+        TypePokemon::Glace => Relations {
+            super_efficace: &[
+                TypePokemon::Plante,
+                TypePokemon::Sol,
+                TypePokemon::Vol,
+                TypePokemon::Dragon,
+            ],
+            peu_efficace: &[
+                TypePokemon::Feu,
+                TypePokemon::Eau,
+                TypePokemon::Glace,
+                TypePokemon::Acier,
+            ],
+            sans_effet: &[],
+        },
+        TypePokemon::Combat => Relations {
+            super_efficace: &[
+                TypePokemon::Normal,
+                TypePokemon::Glace,
+                TypePokemon::Roche,
+                TypePokemon::Tenebres,
+                TypePokemon::Acier,
+            ],
+            peu_efficace: &[
+                TypePokemon::Poison,
+                TypePokemon::Vol,
+                TypePokemon::Psy,
+                TypePokemon::Insecte,
+                TypePokemon::Fee,
+            ],
+            sans_effet: &[TypePokemon::Spectre],
+        },
+        TypePokemon::Poison => Relations {
+            super_efficace: &[TypePokemon::Plante, TypePokemon::Fee],
+            peu_efficace: &[
+                TypePokemon::Poison,
+                TypePokemon::Sol,
+                TypePokemon::Roche,
+                TypePokemon::Spectre,
+            ],
+            sans_effet: &[TypePokemon::Acier],
+        },
+        TypePokemon::Sol => Relations {
+            super_efficace: &[
+                TypePokemon::Feu,
+                TypePokemon::Electrik,
+                TypePokemon::Poison,
+                TypePokemon::Roche,
+                TypePokemon::Acier,
+            ],
+            peu_efficace: &[TypePokemon::Plante, TypePokemon::Insecte],
+            sans_effet: &[TypePokemon::Vol],
+        },
+        TypePokemon::Vol => Relations {
+            super_efficace: &[
+                TypePokemon::Plante,
+                TypePokemon::Combat,
+                TypePokemon::Insecte,
+            ],
+            peu_efficace: &[
+                TypePokemon::Electrik,
+                TypePokemon::Roche,
+                TypePokemon::Acier,
+            ],
+            sans_effet: &[],
+        },
+        TypePokemon::Psy => Relations {
+            super_efficace: &[TypePokemon::Combat, TypePokemon::Poison],
+            peu_efficace: &[TypePokemon::Psy, TypePokemon::Acier],
+            sans_effet: &[TypePokemon::Tenebres],
+        },
+        TypePokemon::Insecte => Relations {
+            super_efficace: &[TypePokemon::Plante, TypePokemon::Psy, TypePokemon::Tenebres],
+            peu_efficace: &[
+                TypePokemon::Feu,
+                TypePokemon::Combat,
+                TypePokemon::Poison,
+                TypePokemon::Vol,
+                TypePokemon::Spectre,
+                TypePokemon::Acier,
+                TypePokemon::Fee,
+            ],
+            sans_effet: &[],
+        },
+        TypePokemon::Roche => Relations {
+            super_efficace: &[
+                TypePokemon::Feu,
+                TypePokemon::Glace,
+                TypePokemon::Vol,
+                TypePokemon::Insecte,
+            ],
+            peu_efficace: &[TypePokemon::Combat, TypePokemon::Sol, TypePokemon::Acier],
+            sans_effet: &[],
+        },
+        TypePokemon::Spectre => Relations {
+            super_efficace: &[TypePokemon::Psy, TypePokemon::Spectre],
+            peu_efficace: &[TypePokemon::Tenebres],
+            sans_effet: &[TypePokemon::Normal],
+        },
+        TypePokemon::Dragon => Relations {
+            super_efficace: &[TypePokemon::Dragon],
+            peu_efficace: &[TypePokemon::Acier],
+            sans_effet: &[TypePokemon::Fee],
+        },
+        TypePokemon::Tenebres => Relations {
+            super_efficace: &[TypePokemon::Psy, TypePokemon::Spectre],
+            peu_efficace: &[TypePokemon::Combat, TypePokemon::Tenebres, TypePokemon::Fee],
+            sans_effet: &[],
+        },
+        TypePokemon::Acier => Relations {
+            super_efficace: &[TypePokemon::Glace, TypePokemon::Roche, TypePokemon::Fee],
+            peu_efficace: &[
+                TypePokemon::Feu,
+                TypePokemon::Eau,
+                TypePokemon::Electrik,
+                TypePokemon::Acier,
+            ],
+            sans_effet: &[],
+        },
+        TypePokemon::Fee => Relations {
+            super_efficace: &[
+                TypePokemon::Combat,
+                TypePokemon::Dragon,
+                TypePokemon::Tenebres,
+            ],
+            peu_efficace: &[TypePokemon::Feu, TypePokemon::Poison, TypePokemon::Acier],
+            sans_effet: &[],
+        },
+    }
+}
+
+// Multiplicateur des dégâts d'une attaque d'un type contre un Pokémon d'un type
+pub fn efficacite(attaque: &TypePokemon, defense: &TypePokemon) -> f32 {
+    let relations = relations(attaque);
+    if relations.super_efficace.contains(defense) {
+        2.0
+    } else if relations.peu_efficace.contains(defense) {
+        0.5
+    } else if relations.sans_effet.contains(defense) {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+// Multiplicateur contre un Pokémon à un ou deux types (produit des deux multiplicateurs)
+pub fn efficacite_double(
+    attaque: &TypePokemon,
+    defense: &TypePokemon,
+    secondaire: Option<&TypePokemon>,
+) -> f32 {
+    efficacite(attaque, defense)
+        * secondaire.map_or(1.0, |type_secondaire| efficacite(attaque, type_secondaire))
+}
+
+// Description d'un multiplicateur, comme en combat
+pub fn libelle_efficacite(multiplicateur: f32) -> &'static str {
+    if multiplicateur == 0.0 {
+        "Ça n'affecte pas le Pokémon..."
+    } else if multiplicateur > 1.0 {
+        "C'est super efficace!"
+    } else if multiplicateur < 1.0 {
+        "Ce n'est pas très efficace..."
+    } else {
+        "Efficacité normale"
+    }
+}
+
+impl Pokemon {
+    // Multiplicateur des dégâts d'une attaque d'un type contre ce Pokémon
+    pub fn efficacite_recue(&self, attaque: &TypePokemon) -> f32 {
+        efficacite_double(attaque, &self.type_pokemon, self.type_secondaire.as_ref())
+    }
+}
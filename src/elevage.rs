@@ -0,0 +1,290 @@
+use std::cmp::Reverse;
+use std::error::Error;
+
+use crate::embarque::DonneesEmbarquees;
+use crate::entrainement::{EV_PAR_SEANCE, SeanceEntrainement};
+use crate::especes::SpeciesSource;
+use crate::genealogie::Filiation;
+use crate::oeuf::{CYCLES_ECLOSION_PAR_DEFAUT, Oeuf, PAS_PAR_SEANCE};
+use crate::pokemon::{Genre, Pokemon, tirer_chromatique};
+use crate::regles::{PolitiqueConsanguinite, ReglesElevage};
+use crate::reproduction::reproduction_avec_regles;
+
+// Partie 4: Gestion de l'élevage
+pub struct Elevage {
+    pub pokemon: Vec<Pokemon>,
+    // Œufs pondus, en attente d'éclosion
+    pub oeufs: Vec<Oeuf>,
+    pub regles: ReglesElevage,
+    // Filiation des Pokémon relâchés, pour la parenté de leurs descendants
+    pub relaches: Vec<Filiation>,
+    source: Box<dyn SpeciesSource>,
+    prochain_id: u32,
+}
+
+impl Default for Elevage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Elevage {
+    // Créer un nouvel élevage (données embarquées par défaut)
+    pub fn new() -> Self {
+        Self::avec_source(Box::new(DonneesEmbarquees::new()))
+    }
+
+    // Créer un nouvel élevage utilisant une source d'espèces donnée
+    pub fn avec_source(source: Box<dyn SpeciesSource>) -> Self {
+        Elevage {
+            pokemon: Vec::new(),
+            oeufs: Vec::new(),
+            regles: ReglesElevage::default(),
+            relaches: Vec::new(),
+            source,
+            prochain_id: 1,
+        }
+    }
+
+    // Source d'espèces utilisée par l'élevage
+    pub fn source(&self) -> &dyn SpeciesSource {
+        self.source.as_ref()
+    }
+
+    // Identifiant qui sera attribué au prochain Pokémon
+    pub fn prochain_id(&self) -> u32 {
+        self.prochain_id
+    }
+
+    // Ajouter un Pokémon aléatoire issu de la source d'espèces
+    pub fn ajouter_aleatoire(&mut self, nom: Option<String>) -> Result<u32, Box<dyn Error>> {
+        let pokemon = Pokemon::generer_aleatoire(self.source(), nom)?;
+        Ok(self.ajouter_pokemon(pokemon))
+    }
+
+    // Ajouter un Pokémon à l'élevage, renvoie son identifiant
+    // (il peut être chromatique selon les règles de l'élevage)
+    pub fn ajouter_pokemon(&mut self, mut pokemon: Pokemon) -> u32 {
+        pokemon.chromatique |= tirer_chromatique(self.regles.chances_chromatique, 1);
+        println!("{} a été ajouté à l'élevage!", pokemon.nom);
+        self.inserer(pokemon)
+    }
+
+    // Attribuer un nouvel identifiant au Pokémon et le stocker
+    pub(crate) fn inserer(&mut self, mut pokemon: Pokemon) -> u32 {
+        pokemon.id = self.prochain_id;
+        self.prochain_id += 1;
+        self.pokemon.push(pokemon);
+        self.prochain_id - 1
+    }
+
+    // Remplacer tous les Pokémon en conservant leurs identifiants s'ils sont valides;
+    // aucun identifiant déjà attribué (Pokémon, œufs ou Pokémon relâchés) n'est réutilisé
+    pub(crate) fn remplacer_pokemon(&mut self, pokemons: Vec<Pokemon>, prochain_id: Option<u32>) {
+        self.pokemon.clear();
+        let plus_grand = pokemons
+            .iter()
+            .chain(self.oeufs.iter().map(|oeuf| &oeuf.pokemon))
+            .map(Filiation::de)
+            .chain(self.relaches.iter().copied())
+            .flat_map(|f| [Some(f.id), f.mere_id, f.pere_id])
+            .flatten()
+            .max()
+            .unwrap_or(0);
+        self.prochain_id = prochain_id.unwrap_or(1).max(plus_grand + 1);
+        for pokemon in pokemons {
+            if pokemon.id == 0 || self.pokemon_par_id(pokemon.id).is_some() {
+                self.inserer(pokemon);
+            } else {
+                self.pokemon.push(pokemon);
+            }
+        }
+    }
+
+    // Retrouver un Pokémon par son identifiant
+    pub fn pokemon_par_id(&self, id: u32) -> Option<&Pokemon> {
+        self.pokemon.iter().find(|p| p.id == id)
+    }
+
+    // Retrouver un Pokémon par son identifiant, pour le modifier
+    pub fn pokemon_par_id_mut(&mut self, id: u32) -> Option<&mut Pokemon> {
+        self.pokemon.iter_mut().find(|p| p.id == id)
+    }
+
+    // Retirer un Pokémon de l'élevage
+    pub fn retirer_pokemon(&mut self, id: u32) -> Option<Pokemon> {
+        let position = self.pokemon.iter().position(|p| p.id == id)?;
+        let pokemon = self.pokemon.remove(position);
+        self.relaches.push(Filiation::de(&pokemon));
+        println!("{} a quitté l'élevage!", pokemon.nom);
+        Some(pokemon)
+    }
+
+    // Afficher tous les Pokémon de l'élevage
+    pub fn afficher_tous_pokemon(&self) {
+        if self.pokemon.is_empty() {
+            println!("L'élevage est vide!");
+            return;
+        }
+
+        println!("\n===== LISTE DES POKEMON =====");
+        for pokemon in &self.pokemon {
+            if pokemon.chromatique {
+                println!("Pokemon #{} ★", pokemon.id);
+            } else {
+                println!("Pokemon #{}", pokemon.id);
+            }
+            pokemon.afficher();
+        }
+    }
+
+    // Afficher les statistiques de l'élevage
+    pub fn afficher_statistiques(&self) {
+        let nombre = self.pokemon.len();
+        let compter = |genre: Genre| self.pokemon.iter().filter(|p| p.genre == genre).count();
+        let chromatiques = self.pokemon.iter().filter(|p| p.chromatique).count();
+
+        println!("\n===== STATISTIQUES DE L'ÉLEVAGE =====");
+        println!("Pokémon: {}", nombre);
+        println!("Œufs: {}", self.oeufs.len());
+        println!(
+            "Mâles: {} / Femelles: {} / Asexués: {}",
+            compter(Genre::Male),
+            compter(Genre::Femelle),
+            compter(Genre::Inconnu)
+        );
+        println!("Chromatiques: {}", chromatiques);
+        if nombre > 0 {
+            let niveau_moyen = self
+                .pokemon
+                .iter()
+                .map(|p| f64::from(p.niveau))
+                .sum::<f64>()
+                / nombre as f64;
+            println!("Niveau moyen: {:.1}", niveau_moyen);
+            println!(
+                "Génération la plus récente: {}",
+                self.pokemon.iter().map(|p| p.generation).max().unwrap_or(0)
+            );
+        }
+    }
+
+    // Entraîner tous les Pokémon (gain d'XP)
+    pub fn entrainer_tous_pokemon(&mut self, xp: u32) {
+        self.entrainer_tous_pokemon_seance(xp, SeanceEntrainement::Libre);
+    }
+
+    // Entraîner tous les Pokémon avec une séance donnée (gain d'XP, et d'EV si la séance est ciblée);
+    // les œufs avancent pendant la séance
+    pub fn entrainer_tous_pokemon_seance(&mut self, xp: u32, seance: SeanceEntrainement) {
+        if self.pokemon.is_empty() {
+            println!("Aucun Pokémon à entraîner!");
+            return;
+        }
+
+        println!("{} pour tous les Pokémon...", seance);
+        for pokemon in &mut self.pokemon {
+            pokemon.gagner_xp(xp);
+            if let Some(stat) = seance.cible() {
+                let gagnes = pokemon.gagner_evs(stat, EV_PAR_SEANCE);
+                if gagnes > 0 {
+                    println!("{} gagne {} EV en {}.", pokemon.nom, gagnes, stat);
+                } else {
+                    println!("{} ne peut plus gagner d'EV en {}.", pokemon.nom, stat);
+                }
+            }
+        }
+        println!("Entraînement terminé!");
+        self.faire_avancer_oeufs(PAS_PAR_SEANCE);
+    }
+
+    // Tenter une reproduction entre deux Pokémon désignés par leur identifiant
+    pub fn tenter_reproduction(&mut self, id1: u32, id2: u32) -> bool {
+        // Cloner les Pokémon pour éviter les problèmes d'emprunt
+        let (Some(pokemon1), Some(pokemon2)) = (
+            self.pokemon_par_id(id1).cloned(),
+            self.pokemon_par_id(id2).cloned(),
+        ) else {
+            println!("Identifiants invalides!");
+            return false;
+        };
+
+        println!(
+            "Tentative de reproduction entre {} et {}...",
+            pokemon1.nom, pokemon2.nom
+        );
+
+        // Vérifier la consanguinité du couple
+        let consanguinite = self.coefficient_consanguinite(id1, id2);
+        if consanguinite > 0.0 {
+            println!(
+                "Coefficient de consanguinité du petit: {:.2}%",
+                consanguinite * 100.0
+            );
+        }
+        let trop_proches = consanguinite > 0.0 && consanguinite >= self.regles.seuil_consanguinite;
+        if trop_proches {
+            match self.regles.politique_consanguinite {
+                PolitiqueConsanguinite::Avertir => {
+                    println!("Attention: ces Pokémon sont proches parents!")
+                }
+                PolitiqueConsanguinite::Penaliser => {
+                    println!("Ces Pokémon sont proches parents: le petit sera plus faible.")
+                }
+                PolitiqueConsanguinite::Interdire => {
+                    println!("Reproduction refusée: ces Pokémon sont proches parents.");
+                    return false;
+                }
+            }
+        }
+
+        // Tenter la reproduction
+        if let Some(mut bebe) = reproduction_avec_regles(&pokemon1, &pokemon2, &self.regles) {
+            if trop_proches
+                && self.regles.politique_consanguinite == PolitiqueConsanguinite::Penaliser
+            {
+                bebe.malus_consanguinite = consanguinite;
+                bebe.recalculer_stats();
+            }
+            // Le petit naîtra d'un œuf, après le nombre de cycles de son espèce
+            let cycles = bebe
+                .espece_id
+                .and_then(|id| self.source().espece(id).ok())
+                .and_then(|espece| espece.cycles_eclosion)
+                .unwrap_or(CYCLES_ECLOSION_PAR_DEFAUT);
+            let oeuf = Oeuf::new(bebe, cycles);
+            println!(
+                "Félicitations! Un œuf a été pondu! Il éclora dans {} pas.",
+                oeuf.pas_restants
+            );
+            self.oeufs.push(oeuf);
+            true
+        } else {
+            println!("La reproduction a échoué. Les Pokémon ne sont pas compatibles.");
+            false
+        }
+    }
+
+    // Bonus: Trier les Pokémon par niveau
+    pub fn trier_par_niveau(&mut self) {
+        self.pokemon.sort_by_key(|p| Reverse(p.niveau));
+        println!("Pokémon triés par niveau (décroissant)!");
+    }
+
+    // Bonus: Trier les Pokémon par type
+    pub fn trier_par_type(&mut self) {
+        // Tri sur le type primaire, puis sur le type secondaire
+        self.pokemon.sort_by(|a, b| {
+            let type_a = (
+                a.type_pokemon.to_string(),
+                a.type_secondaire.as_ref().map(|t| t.to_string()),
+            );
+            let type_b = (
+                b.type_pokemon.to_string(),
+                b.type_secondaire.as_ref().map(|t| t.to_string()),
+            );
+            type_a.cmp(&type_b)
+        });
+        println!("Pokémon triés par type!");
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::api::{
+    convert_croissance_from_api, convert_groupe_oeuf_from_api, convert_type_from_api,
+};
+use crate::attaques::{AttaqueApprise, nom_attaque};
+use crate::especes::{Espece, SpeciesSource, capitaliser};
+use crate::evolution::{Declencheur, Evolution};
+use crate::pokemon::RepartitionGenres;
+use crate::stats::Stats;
+use crate::talent::Talent;
+
+// Données des 151 premiers Pokémon, embarquées dans le binaire
+const ESPECES_GEN1: &str = include_str!("donnees/especes_gen1.txt");
+const STATS_DE_BASE_GEN1: &str = include_str!("donnees/stats_de_base_gen1.txt");
+const CROISSANCE_GEN1: &str = include_str!("donnees/croissance_gen1.txt");
+const EVOLUTIONS_GEN1: &str = include_str!("donnees/evolutions_gen1.txt");
+const GROUPES_OEUF_GEN1: &str = include_str!("donnees/groupes_oeuf_gen1.txt");
+const ECLOSION_GEN1: &str = include_str!("donnees/eclosion_gen1.txt");
+const GENRES_GEN1: &str = include_str!("donnees/genres_gen1.txt");
+// Attaques apprises par niveau (Rouge/Bleu), pour une partie des espèces seulement
+const ATTAQUES_GEN1: &str = include_str!("donnees/attaques_gen1.txt");
+// Attaques Œuf (Or/Argent) des espèces de base, pour une partie des espèces seulement
+const ATTAQUES_OEUF_GEN1: &str = include_str!("donnees/attaques_oeuf_gen1.txt");
+// Talents (talent caché suivi de ":cache"), pour une partie des espèces seulement
+const TALENTS_GEN1: &str = include_str!("donnees/talents_gen1.txt");
+
+// Source hors-ligne basée sur les données embarquées
+#[derive(Debug, Clone)]
+pub struct DonneesEmbarquees {
+    especes: Vec<Espece>,
+}
+
+impl Default for DonneesEmbarquees {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DonneesEmbarquees {
+    pub fn new() -> Self {
+        let stats_de_base = table(STATS_DE_BASE_GEN1);
+        let croissance = table(CROISSANCE_GEN1);
+        let groupes_oeuf = table(GROUPES_OEUF_GEN1);
+        let eclosion = table(ECLOSION_GEN1);
+        let genres = table(GENRES_GEN1);
+        let attaques = table(ATTAQUES_GEN1);
+        let attaques_oeuf = table(ATTAQUES_OEUF_GEN1);
+        let talents = table(TALENTS_GEN1);
+
+        // Format d'une ligne: id|nom|type1,type2
+        let mut especes: Vec<Espece> = ESPECES_GEN1
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split('|').collect();
+                if parts.len() != 3 {
+                    return None;
+                }
+                let id = parts[0].parse().ok()?;
+                Some(Espece {
+                    id,
+                    nom: capitaliser(parts[1]),
+                    types: parts[2].split(',').map(convert_type_from_api).collect(),
+                    stats_de_base: stats_de_base
+                        .get(&id)
+                        .and_then(|stats| Stats::depuis_texte(stats))
+                        .unwrap_or_default(),
+                    croissance: croissance
+                        .get(&id)
+                        .and_then(|courbe| convert_croissance_from_api(courbe))
+                        .unwrap_or_default(),
+                    evolutions: Vec::new(),
+                    groupes_oeuf: groupes_oeuf
+                        .get(&id)
+                        .map(|groupes| {
+                            groupes
+                                .split(',')
+                                .filter_map(convert_groupe_oeuf_from_api)
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    cycles_eclosion: eclosion.get(&id).and_then(|cycles| cycles.parse().ok()),
+                    repartition_genres: genres
+                        .get(&id)
+                        .and_then(|taux| taux.parse().ok())
+                        .map(RepartitionGenres::depuis_taux)
+                        .unwrap_or_default(),
+                    // Format: niveau:attaque,...
+                    apprentissage: attaques
+                        .get(&id)
+                        .map(|liste| {
+                            liste
+                                .split(',')
+                                .filter_map(|attaque| {
+                                    let (niveau, nom) = attaque.split_once(':')?;
+                                    Some(AttaqueApprise {
+                                        niveau: niveau.parse().ok()?,
+                                        nom: nom_attaque(nom),
+                                    })
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    attaques_oeuf: attaques_oeuf
+                        .get(&id)
+                        .map(|liste| liste.split(',').map(nom_attaque).collect())
+                        .unwrap_or_default(),
+                    talents: talents
+                        .get(&id)
+                        .map(|liste| {
+                            liste
+                                .split(',')
+                                .map(|talent| match talent.strip_suffix(":cache") {
+                                    Some(nom) => Talent::depuis_api(nom, true),
+                                    None => Talent::depuis_api(talent, false),
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        // Format d'une ligne: id|cible:declencheur,... (le nom de la cible vient de la liste des espèces)
+        let evolutions = table(EVOLUTIONS_GEN1);
+        let noms: HashMap<u32, String> = especes
+            .iter()
+            .map(|espece| (espece.id, espece.nom.clone()))
+            .collect();
+        for espece in &mut especes {
+            if let Some(liste) = evolutions.get(&espece.id) {
+                espece.evolutions = liste
+                    .split(',')
+                    .filter_map(|evolution| {
+                        let (cible, declencheur) = evolution.split_once(':')?;
+                        let espece_id = cible.parse().ok()?;
+                        Some(Evolution {
+                            espece_id,
+                            nom_espece: noms.get(&espece_id)?.clone(),
+                            declencheur: Declencheur::depuis_texte(declencheur)?,
+                        })
+                    })
+                    .collect();
+            }
+        }
+
+        DonneesEmbarquees { especes }
+    }
+}
+
+impl SpeciesSource for DonneesEmbarquees {
+    fn espece(&self, id: u32) -> Result<Espece, Box<dyn Error>> {
+        self.especes
+            .iter()
+            .find(|espece| espece.id == id)
+            .cloned()
+            .ok_or_else(|| format!("Espèce #{} absente des données embarquées", id).into())
+    }
+
+    fn liste_especes(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(self
+            .especes
+            .iter()
+            .map(|espece| espece.nom.clone())
+            .collect())
+    }
+
+    fn identifiants(&self) -> Vec<u32> {
+        self.especes.iter().map(|espece| espece.id).collect()
+    }
+}
+
+// Table embarquée au format "id|valeurs", indexée par numéro de Pokédex
+fn table(donnees: &'static str) -> HashMap<u32, &'static str> {
+    donnees
+        .lines()
+        .filter_map(|line| {
+            let (id, valeurs) = line.split_once('|')?;
+            Some((id.parse().ok()?, valeurs))
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use std::fmt;
+
+use crate::stats::Statistique;
+
+// EV gagnés par statistique lors d'une séance ciblée
+pub const EV_PAR_SEANCE: u32 = 10;
+
+// Type de séance d'entraînement: libre (XP seulement) ou ciblée sur une statistique (XP et EV)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeanceEntrainement {
+    Libre,
+    Ciblee(Statistique),
+}
+
+impl SeanceEntrainement {
+    pub const TOUTES: [SeanceEntrainement; 7] = [
+        SeanceEntrainement::Libre,
+        SeanceEntrainement::Ciblee(Statistique::Pv),
+        SeanceEntrainement::Ciblee(Statistique::Attaque),
+        SeanceEntrainement::Ciblee(Statistique::Defense),
+        SeanceEntrainement::Ciblee(Statistique::AttaqueSpe),
+        SeanceEntrainement::Ciblee(Statistique::DefenseSpe),
+        SeanceEntrainement::Ciblee(Statistique::Vitesse),
+    ];
+
+    // Statistique travaillée par la séance
+    pub fn cible(&self) -> Option<Statistique> {
+        match self {
+            SeanceEntrainement::Libre => None,
+            SeanceEntrainement::Ciblee(stat) => Some(*stat),
+        }
+    }
+}
+
+impl fmt::Display for SeanceEntrainement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SeanceEntrainement::Libre => write!(f, "Entraînement libre"),
+            SeanceEntrainement::Ciblee(stat) => {
+                let nom = match stat {
+                    Statistique::Pv => "Course d'endurance",
+                    Statistique::Attaque => "Musculation",
+                    Statistique::Defense => "Parades",
+                    Statistique::AttaqueSpe => "Concentration",
+                    Statistique::DefenseSpe => "Méditation",
+                    Statistique::Vitesse => "Sprint",
+                };
+                write!(f, "{} ({})", nom, stat)
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::error::Error;
+
+use crate::attaques::AttaqueApprise;
+use crate::croissance::CourbeCroissance;
+use crate::evolution::Evolution;
+use crate::groupe_oeuf::GroupeOeuf;
+use crate::pokemon::{RepartitionGenres, TypePokemon};
+use crate::stats::Stats;
+use crate::talent::Talent;
+
+// Données d'une espèce, quelle que soit leur provenance
+#[derive(Debug, Clone, Default)]
+pub struct Espece {
+    pub id: u32,
+    pub nom: String,
+    pub types: Vec<TypePokemon>,
+    pub stats_de_base: Stats,
+    pub croissance: CourbeCroissance,
+    // Évolutions directes de l'espèce
+    pub evolutions: Vec<Evolution>,
+    pub groupes_oeuf: Vec<GroupeOeuf>,
+    // Cycles d'éclosion des œufs de l'espèce, s'ils sont connus
+    pub cycles_eclosion: Option<u32>,
+    pub repartition_genres: RepartitionGenres,
+    // Attaques apprises en montant de niveau, par niveau croissant
+    pub apprentissage: Vec<AttaqueApprise>,
+    // Attaques Œuf: attaques que les petits de l'espèce peuvent hériter de leur père
+    pub attaques_oeuf: Vec<String>,
+    // Talents possibles de l'espèce, dont éventuellement un talent caché
+    pub talents: Vec<Talent>,
+}
+
+// Source de données des espèces (API en ligne, données embarquées, fausse source...)
+pub trait SpeciesSource {
+    // Récupérer une espèce par son numéro de Pokédex
+    fn espece(&self, id: u32) -> Result<Espece, Box<dyn Error>>;
+
+    // Noms de toutes les espèces disponibles, dans l'ordre du Pokédex
+    fn liste_especes(&self) -> Result<Vec<String>, Box<dyn Error>>;
+
+    // Numéros de Pokédex disponibles (par défaut les 151 premiers)
+    fn identifiants(&self) -> Vec<u32> {
+        (1..=151).collect()
+    }
+
+    // Récupérer toutes les espèces une fois (remplit le cache des sources qui en ont un)
+    fn precharger(&self) -> Result<usize, Box<dyn Error>> {
+        self.liste_especes()?;
+        let identifiants = self.identifiants();
+        for id in &identifiants {
+            self.espece(*id)?;
+        }
+        Ok(identifiants.len())
+    }
+}
+
+// Source en mémoire, utile pour les tests et les démonstrations
+#[derive(Debug, Clone, Default)]
+pub struct SourceMemoire {
+    especes: Vec<Espece>,
+}
+
+impl SourceMemoire {
+    pub fn new(especes: Vec<Espece>) -> Self {
+        SourceMemoire { especes }
+    }
+
+    // Ajouter une espèce à la source
+    pub fn ajouter(&mut self, espece: Espece) {
+        self.especes.push(espece);
+    }
+}
+
+impl SpeciesSource for SourceMemoire {
+    fn espece(&self, id: u32) -> Result<Espece, Box<dyn Error>> {
+        self.especes
+            .iter()
+            .find(|espece| espece.id == id)
+            .cloned()
+            .ok_or_else(|| format!("Espèce #{} introuvable", id).into())
+    }
+
+    fn liste_especes(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(self
+            .especes
+            .iter()
+            .map(|espece| espece.nom.clone())
+            .collect())
+    }
+
+    fn identifiants(&self) -> Vec<u32> {
+        self.especes.iter().map(|espece| espece.id).collect()
+    }
+}
+
+// Choisir une source à partir de son nom ("api" ou "embarque")
+pub fn source_depuis_nom(nom: &str) -> Option<Box<dyn SpeciesSource>> {
+    match nom.to_lowercase().as_str() {
+        "api" => Some(Box::new(
+            crate::api::PokeApi::avec_config(crate::config::ConfigApi::charger())
+                .avec_cache(crate::cache::CacheHttp::par_defaut()),
+        )),
+        "embarque" | "hors-ligne" => Some(Box::new(crate::embarque::DonneesEmbarquees::new())),
+        _ => None,
+    }
+}
+
+// Première lettre en majuscule pour les noms d'espèces
+pub(crate) fn capitaliser(nom: &str) -> String {
+    nom.chars()
+        .next()
+        .unwrap_or('p')
+        .to_uppercase()
+        .collect::<String>()
+        + &nom[1..]
+}
@@ -0,0 +1,147 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::elevage::Elevage;
+
+// Condition déclenchant une évolution
+#[derive(Debug, Clone, PartialEq)]
+pub enum Declencheur {
+    Niveau(u32),
+    // Objet utilisé, sous son nom PokéAPI ("fire-stone"...)
+    Pierre(String),
+    Echange,
+    Bonheur,
+    Autre,
+}
+
+impl Declencheur {
+    // Format de sauvegarde: "niveau-16", "pierre-fire-stone", "echange", "bonheur" ou "autre"
+    pub fn en_texte(&self) -> String {
+        match self {
+            Declencheur::Niveau(niveau) => format!("niveau-{}", niveau),
+            Declencheur::Pierre(objet) => format!("pierre-{}", objet),
+            Declencheur::Echange => "echange".to_string(),
+            Declencheur::Bonheur => "bonheur".to_string(),
+            Declencheur::Autre => "autre".to_string(),
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Self> {
+        if let Some(niveau) = texte.strip_prefix("niveau-") {
+            return niveau.parse().ok().map(Declencheur::Niveau);
+        }
+        if let Some(objet) = texte.strip_prefix("pierre-") {
+            return Some(Declencheur::Pierre(objet.to_string()));
+        }
+        match texte {
+            "echange" => Some(Declencheur::Echange),
+            "bonheur" => Some(Declencheur::Bonheur),
+            "autre" => Some(Declencheur::Autre),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Declencheur {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Declencheur::Niveau(niveau) => write!(f, "Niveau {}", niveau),
+            Declencheur::Pierre(objet) => match objet.as_str() {
+                "fire-stone" => write!(f, "Pierre Feu"),
+                "water-stone" => write!(f, "Pierre Eau"),
+                "thunder-stone" => write!(f, "Pierre Foudre"),
+                "leaf-stone" => write!(f, "Pierre Plante"),
+                "moon-stone" => write!(f, "Pierre Lune"),
+                _ => write!(f, "Objet {}", objet),
+            },
+            Declencheur::Echange => write!(f, "Échange"),
+            Declencheur::Bonheur => write!(f, "Bonheur"),
+            Declencheur::Autre => write!(f, "Autre"),
+        }
+    }
+}
+
+// Évolution possible vers une autre espèce
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evolution {
+    pub espece_id: u32,
+    pub nom_espece: String,
+    pub declencheur: Declencheur,
+}
+
+impl Evolution {
+    // Format de sauvegarde: "2:Ivysaur:niveau-16"
+    pub fn en_texte(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.espece_id,
+            self.nom_espece,
+            self.declencheur.en_texte()
+        )
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Self> {
+        let mut parts = texte.splitn(3, ':');
+        Some(Evolution {
+            espece_id: parts.next()?.parse().ok()?,
+            nom_espece: parts.next()?.to_string(),
+            declencheur: Declencheur::depuis_texte(parts.next()?)?,
+        })
+    }
+}
+
+// Liste d'évolutions au format "2:Ivysaur:niveau-16,3:Venusaur:niveau-32"
+pub fn evolutions_en_texte(evolutions: &[Evolution]) -> String {
+    evolutions
+        .iter()
+        .map(Evolution::en_texte)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn evolutions_depuis_texte(texte: &str) -> Vec<Evolution> {
+    texte
+        .split(',')
+        .filter_map(Evolution::depuis_texte)
+        .collect()
+}
+
+impl Elevage {
+    // Pokémon prêts à évoluer, avec leur évolution
+    pub fn evolutions_en_attente(&self) -> Vec<(u32, Evolution)> {
+        self.pokemon
+            .iter()
+            .filter_map(|pokemon| {
+                pokemon
+                    .evolution_en_attente
+                    .clone()
+                    .map(|evolution| (pokemon.id, evolution))
+            })
+            .collect()
+    }
+
+    // Faire évoluer un Pokémon prêt à évoluer, avec les données de l'espèce obtenue
+    pub fn faire_evoluer(&mut self, id: u32) -> Result<(), Box<dyn Error>> {
+        let evolution = self
+            .pokemon_par_id(id)
+            .ok_or_else(|| format!("Aucun Pokémon avec l'ID {}", id))?
+            .evolution_en_attente
+            .clone()
+            .ok_or_else(|| format!("Le Pokémon #{} n'est pas prêt à évoluer", id))?;
+        let espece = self.source().espece(evolution.espece_id)?;
+
+        if let Some(pokemon) = self.pokemon_par_id_mut(id) {
+            pokemon.evoluer(&espece);
+        }
+        Ok(())
+    }
+
+    // Annuler l'évolution d'un Pokémon (elle sera reproposée au prochain niveau)
+    pub fn annuler_evolution(&mut self, id: u32) {
+        if let Some(pokemon) = self.pokemon_par_id_mut(id)
+            && pokemon.evolution_en_attente.take().is_some()
+        {
+            println!("{} n'a pas évolué.", pokemon.nom);
+        }
+    }
+}
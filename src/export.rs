@@ -0,0 +1,92 @@
+use std::fs;
+use std::io;
+
+use crate::elevage::Elevage;
+use crate::genealogie::Filiation;
+use crate::pokemon::Pokemon;
+
+impl Elevage {
+    // Graphe de reproduction au format Graphviz DOT
+    pub fn pedigree_dot(&self) -> String {
+        let mut dot = String::from("digraph pedigree {\n    node [shape=box];\n");
+        for pokemon in &self.pokemon {
+            // '\' puis '"' sont échappés dans chaque ligne, avant de les joindre par "\n"
+            let label = lignes_label(pokemon)
+                .iter()
+                .map(|ligne| ligne.replace('\\', "\\\\").replace('"', "\\\""))
+                .collect::<Vec<_>>()
+                .join("\\n");
+            dot.push_str(&format!("    p{} [label=\"{}\"];\n", pokemon.id, label));
+        }
+        for (id, _) in self.ancetres_absents() {
+            dot.push_str(&format!(
+                "    p{} [label=\"#{}\\n(absent)\", style=dashed];\n",
+                id, id
+            ));
+        }
+        for (parent, enfant, role) in self.liens() {
+            dot.push_str(&format!(
+                "    p{} -> p{} [label=\"{}\"];\n",
+                parent, enfant, role
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    // Graphe de reproduction au format Mermaid
+    pub fn pedigree_mermaid(&self) -> String {
+        let mut mermaid = String::from("graph TD\n");
+        for pokemon in &self.pokemon {
+            // '#' et '"' doivent être échappés sous forme d'entités Mermaid
+            let label = lignes_label(pokemon)
+                .join("<br/>")
+                .replace('#', "#35;")
+                .replace('"', "#quot;");
+            mermaid.push_str(&format!("    p{}[\"{}\"]\n", pokemon.id, label));
+        }
+        for (id, _) in self.ancetres_absents() {
+            mermaid.push_str(&format!("    p{}[\"#35;{}<br/>(absent)\"]\n", id, id));
+        }
+        for (parent, enfant, role) in self.liens() {
+            mermaid.push_str(&format!("    p{} -->|{}| p{}\n", parent, role, enfant));
+        }
+        mermaid
+    }
+
+    // Écrire le pedigree dans `<base>.dot` et `<base>.mmd`
+    pub fn exporter_pedigree(&self, base: &str) -> Result<(), io::Error> {
+        fs::write(format!("{}.dot", base), self.pedigree_dot())?;
+        fs::write(format!("{}.mmd", base), self.pedigree_mermaid())?;
+        println!("Pedigree exporté dans '{0}.dot' et '{0}.mmd'!", base);
+        Ok(())
+    }
+
+    // Liens parent -> enfant, avec le rôle du parent (y compris entre ancêtres relâchés)
+    fn liens(&self) -> Vec<(u32, u32, &'static str)> {
+        let absents = self
+            .ancetres_absents()
+            .into_iter()
+            .filter_map(|(_, filiation)| filiation);
+        let mut liens = Vec::new();
+        for filiation in self.pokemon.iter().map(Filiation::de).chain(absents) {
+            if let Some(mere) = filiation.mere_id {
+                liens.push((mere, filiation.id, "mère"));
+            }
+            if let Some(pere) = filiation.pere_id {
+                liens.push((pere, filiation.id, "père"));
+            }
+        }
+        liens
+    }
+}
+
+// Lignes du label d'un nœud: nom, type, niveau et genre
+fn lignes_label(pokemon: &Pokemon) -> Vec<String> {
+    vec![
+        format!("#{} {}", pokemon.id, pokemon.nom),
+        pokemon.libelle_types(),
+        format!("Niveau {}", pokemon.niveau),
+        pokemon.genre.to_string(),
+    ]
+}
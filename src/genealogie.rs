@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::elevage::Elevage;
+use crate::pokemon::Pokemon;
+
+// Profondeur maximale affichée dans l'arbre généalogique
+const PROFONDEUR_MAX: usize = 10;
+
+// Filiation d'un Pokémon: conservée après son départ pour ne pas perdre la parenté de ses descendants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Filiation {
+    pub id: u32,
+    pub mere_id: Option<u32>,
+    pub pere_id: Option<u32>,
+    pub generation: u32,
+}
+
+impl Filiation {
+    pub fn de(pokemon: &Pokemon) -> Self {
+        Filiation {
+            id: pokemon.id,
+            mere_id: pokemon.mere_id,
+            pere_id: pokemon.pere_id,
+            generation: pokemon.generation,
+        }
+    }
+
+    fn parents(&self) -> impl Iterator<Item = u32> {
+        [self.mere_id, self.pere_id].into_iter().flatten()
+    }
+}
+
+impl Elevage {
+    // Filiation d'un Pokémon présent dans l'élevage ou relâché
+    pub fn filiation(&self, id: u32) -> Option<Filiation> {
+        self.pokemon_par_id(id)
+            .map(Filiation::de)
+            .or_else(|| self.relaches.iter().find(|f| f.id == id).copied())
+    }
+
+    // Parents d'un Pokémon présents dans l'élevage (mère puis père)
+    pub fn parents(&self, id: u32) -> Vec<&Pokemon> {
+        let Some(pokemon) = self.pokemon_par_id(id) else {
+            return Vec::new();
+        };
+        [pokemon.mere_id, pokemon.pere_id]
+            .into_iter()
+            .flatten()
+            .filter_map(|parent| self.pokemon_par_id(parent))
+            .collect()
+    }
+
+    // Enfants d'un Pokémon
+    pub fn enfants(&self, id: u32) -> Vec<&Pokemon> {
+        self.pokemon
+            .iter()
+            .filter(|p| p.mere_id == Some(id) || p.pere_id == Some(id))
+            .collect()
+    }
+
+    // Tous les ancêtres d'un Pokémon présents dans l'élevage, des parents vers les plus anciens
+    // (la lignée est suivie à travers les ancêtres relâchés)
+    pub fn ancetres(&self, id: u32) -> Vec<&Pokemon> {
+        self.presents(self.parcourir(id, |elevage, id| {
+            elevage
+                .filiation(id)
+                .map_or(Vec::new(), |f| f.parents().collect())
+        }))
+    }
+
+    // Tous les descendants d'un Pokémon présents dans l'élevage, des enfants vers les plus jeunes
+    pub fn descendants(&self, id: u32) -> Vec<&Pokemon> {
+        self.presents(self.parcourir(id, |elevage, id| {
+            elevage
+                .pokemon
+                .iter()
+                .map(Filiation::de)
+                .chain(elevage.relaches.iter().copied())
+                .filter(|f| f.mere_id == Some(id) || f.pere_id == Some(id))
+                .map(|f| f.id)
+                .collect()
+        }))
+    }
+
+    // Ancêtres relâchés des Pokémon de l'élevage, par identifiant croissant (sans filiation
+    // connue pour ceux qui ont quitté l'élevage avant d'être enregistrés)
+    pub(crate) fn ancetres_absents(&self) -> Vec<(u32, Option<Filiation>)> {
+        let mut absents: Vec<u32> = self
+            .pokemon
+            .iter()
+            .flat_map(|pokemon| {
+                self.parcourir(pokemon.id, |elevage, id| {
+                    elevage
+                        .filiation(id)
+                        .map_or(Vec::new(), |f| f.parents().collect())
+                })
+            })
+            .filter(|id| self.pokemon_par_id(*id).is_none())
+            .collect();
+        absents.sort_unstable();
+        absents.dedup();
+        absents
+            .into_iter()
+            .map(|id| (id, self.filiation(id)))
+            .collect()
+    }
+
+    // Frères et sœurs (ayant au moins un parent en commun)
+    pub fn freres_et_soeurs(&self, id: u32) -> Vec<&Pokemon> {
+        let Some(pokemon) = self.pokemon_par_id(id) else {
+            return Vec::new();
+        };
+        let meme_parent = |a: Option<u32>, b: Option<u32>| a.is_some() && a == b;
+        self.pokemon
+            .iter()
+            .filter(|p| p.id != id)
+            .filter(|p| {
+                meme_parent(p.mere_id, pokemon.mere_id) || meme_parent(p.pere_id, pokemon.pere_id)
+            })
+            .collect()
+    }
+
+    // Coefficient de consanguinité d'un Pokémon (probabilité que ses deux copies d'un gène viennent du même ancêtre)
+    pub fn consanguinite(&self, id: u32) -> f64 {
+        match self.pokemon_par_id(id) {
+            Some(Pokemon {
+                mere_id: Some(mere),
+                pere_id: Some(pere),
+                ..
+            }) => self.coefficient_consanguinite(*mere, *pere),
+            _ => 0.0,
+        }
+    }
+
+    // Coefficient de consanguinité qu'aurait le petit de deux Pokémon (coefficient de parenté de Wright)
+    pub fn coefficient_consanguinite(&self, id1: u32, id2: u32) -> f64 {
+        self.parente(id1, id2, &mut HashMap::new())
+    }
+
+    // Coefficient de parenté entre deux Pokémon, calculé récursivement sur les parents connus
+    fn parente(&self, id1: u32, id2: u32, memo: &mut HashMap<(u32, u32), f64>) -> f64 {
+        let cle = (id1.min(id2), id1.max(id2));
+        if let Some(valeur) = memo.get(&cle) {
+            return *valeur;
+        }
+
+        let (Some(a), Some(b)) = (self.filiation(id1), self.filiation(id2)) else {
+            return 0.0;
+        };
+
+        let valeur = if a.id == b.id {
+            let consanguinite = match (a.mere_id, a.pere_id) {
+                (Some(mere), Some(pere)) => self.parente(mere, pere, memo),
+                _ => 0.0,
+            };
+            (1.0 + consanguinite) / 2.0
+        } else {
+            // On remonte par le plus jeune des deux, qui ne peut pas être l'ancêtre de l'autre
+            let (jeune, autre) = if (a.generation, a.id) >= (b.generation, b.id) {
+                (a, b.id)
+            } else {
+                (b, a.id)
+            };
+            [jeune.mere_id, jeune.pere_id]
+                .into_iter()
+                .map(|parent| parent.map_or(0.0, |parent| self.parente(parent, autre, memo)))
+                .sum::<f64>()
+                / 2.0
+        };
+
+        memo.insert(cle, valeur);
+        valeur
+    }
+
+    // Parcours en largeur à partir d'un Pokémon, sans doublons
+    fn parcourir(&self, id: u32, voisins: impl Fn(&Elevage, u32) -> Vec<u32>) -> Vec<u32> {
+        let mut vus = HashSet::from([id]);
+        let mut resultat = Vec::new();
+        let mut a_visiter = vec![id];
+        while !a_visiter.is_empty() {
+            let mut suivants = Vec::new();
+            for courant in a_visiter {
+                for voisin in voisins(self, courant) {
+                    if vus.insert(voisin) {
+                        resultat.push(voisin);
+                        suivants.push(voisin);
+                    }
+                }
+            }
+            a_visiter = suivants;
+        }
+        resultat
+    }
+
+    // Pokémon encore présents parmi des identifiants
+    fn presents(&self, ids: Vec<u32>) -> Vec<&Pokemon> {
+        ids.into_iter()
+            .filter_map(|id| self.pokemon_par_id(id))
+            .collect()
+    }
+
+    // Afficher l'arbre généalogique d'un Pokémon
+    pub fn afficher_arbre(&self, id: u32) {
+        let Some(pokemon) = self.pokemon_par_id(id) else {
+            println!("Aucun Pokémon avec l'ID {}!", id);
+            return;
+        };
+
+        println!(
+            "\n===== ARBRE GÉNÉALOGIQUE DE {} =====",
+            pokemon.nom.to_uppercase()
+        );
+        println!("{}", libelle(pokemon));
+        let consanguinite = self.consanguinite(id);
+        if consanguinite > 0.0 {
+            println!(
+                "Coefficient de consanguinité: {:.2}%",
+                consanguinite * 100.0
+            );
+        }
+
+        println!("Ancêtres:");
+        if pokemon.mere_id.is_none() && pokemon.pere_id.is_none() {
+            println!("  (fondateur)");
+        }
+        self.afficher_ascendance(Filiation::de(pokemon), 1);
+
+        println!("Descendants:");
+        if self.enfants(id).is_empty() {
+            println!("  (aucun)");
+        }
+        self.afficher_descendance(id, 1);
+
+        let freres = self.freres_et_soeurs(id);
+        if !freres.is_empty() {
+            println!("Frères et sœurs:");
+            for frere in freres {
+                println!("  {}", libelle(frere));
+            }
+        }
+    }
+
+    fn afficher_ascendance(&self, filiation: Filiation, profondeur: usize) {
+        if profondeur > PROFONDEUR_MAX {
+            return;
+        }
+        let indentation = "  ".repeat(profondeur);
+        for (role, parent_id) in [("Mère", filiation.mere_id), ("Père", filiation.pere_id)] {
+            let Some(parent_id) = parent_id else {
+                continue;
+            };
+            match self.pokemon_par_id(parent_id) {
+                Some(parent) => println!("{}{}: {}", indentation, role, libelle(parent)),
+                None => println!(
+                    "{}{}: #{} (n'est plus dans l'élevage)",
+                    indentation, role, parent_id
+                ),
+            }
+            if let Some(parent) = self.filiation(parent_id) {
+                self.afficher_ascendance(parent, profondeur + 1);
+            }
+        }
+    }
+
+    fn afficher_descendance(&self, id: u32, profondeur: usize) {
+        if profondeur > PROFONDEUR_MAX {
+            return;
+        }
+        for enfant in self.enfants(id) {
+            println!("{}{}", "  ".repeat(profondeur), libelle(enfant));
+            self.afficher_descendance(enfant.id, profondeur + 1);
+        }
+    }
+}
+
+// Description courte d'un Pokémon dans l'arbre
+fn libelle(pokemon: &Pokemon) -> String {
+    format!(
+        "#{} {} ({}, {}, génération {})",
+        pokemon.id, pokemon.nom, pokemon.nom_espece, pokemon.genre, pokemon.generation
+    )
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+// Groupes Œuf: deux Pokémon doivent en partager un pour se reproduire
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupeOeuf {
+    Monstre,
+    Eau1,
+    Insecte,
+    Vol,
+    Terrestre,
+    Fee,
+    Plante,
+    Humanoide,
+    Eau3,
+    Mineral,
+    Amorphe,
+    Eau2,
+    // Métamorph, qui peut se reproduire avec presque tous les Pokémon
+    Metamorph,
+    Dragon,
+    // Pokémon qui ne peuvent pas se reproduire (légendaires, bébés...)
+    Inconnu,
+}
+
+impl GroupeOeuf {
+    pub const TOUS: [GroupeOeuf; 15] = [
+        GroupeOeuf::Monstre,
+        GroupeOeuf::Eau1,
+        GroupeOeuf::Insecte,
+        GroupeOeuf::Vol,
+        GroupeOeuf::Terrestre,
+        GroupeOeuf::Fee,
+        GroupeOeuf::Plante,
+        GroupeOeuf::Humanoide,
+        GroupeOeuf::Eau3,
+        GroupeOeuf::Mineral,
+        GroupeOeuf::Amorphe,
+        GroupeOeuf::Eau2,
+        GroupeOeuf::Metamorph,
+        GroupeOeuf::Dragon,
+        GroupeOeuf::Inconnu,
+    ];
+
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        Self::TOUS
+            .into_iter()
+            .find(|groupe| groupe.to_string() == nom)
+    }
+}
+
+impl fmt::Display for GroupeOeuf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GroupeOeuf::Monstre => write!(f, "Monstre"),
+            GroupeOeuf::Eau1 => write!(f, "Aquatique 1"),
+            GroupeOeuf::Insecte => write!(f, "Insectoïde"),
+            GroupeOeuf::Vol => write!(f, "Aérien"),
+            GroupeOeuf::Terrestre => write!(f, "Terrestre"),
+            GroupeOeuf::Fee => write!(f, "Féerique"),
+            GroupeOeuf::Plante => write!(f, "Végétal"),
+            GroupeOeuf::Humanoide => write!(f, "Humanoïde"),
+            GroupeOeuf::Eau3 => write!(f, "Aquatique 3"),
+            GroupeOeuf::Mineral => write!(f, "Minéral"),
+            GroupeOeuf::Amorphe => write!(f, "Amorphe"),
+            GroupeOeuf::Eau2 => write!(f, "Aquatique 2"),
+            GroupeOeuf::Metamorph => write!(f, "Métamorph"),
+            GroupeOeuf::Dragon => write!(f, "Draconique"),
+            GroupeOeuf::Inconnu => write!(f, "Inconnu"),
+        }
+    }
+}
+
+// Groupes au format "Monstre,Végétal"
+pub fn groupes_en_texte(groupes: &[GroupeOeuf]) -> String {
+    groupes
+        .iter()
+        .map(GroupeOeuf::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn groupes_depuis_texte(texte: &str) -> Vec<GroupeOeuf> {
+    texte
+        .split(',')
+        .filter_map(GroupeOeuf::depuis_nom)
+        .collect()
+}
@@ -0,0 +1,29 @@
+// Bibliothèque de gestion d'élevage Pokémon
+pub mod api;
+pub mod attaques;
+pub mod cache;
+pub mod config;
+pub mod croissance;
+pub mod efficacite;
+pub mod elevage;
+pub mod embarque;
+pub mod entrainement;
+pub mod especes;
+pub mod evolution;
+pub mod export;
+pub mod genealogie;
+pub mod groupe_oeuf;
+pub mod nature;
+pub mod objet;
+pub mod oeuf;
+pub mod pokemon;
+pub mod regles;
+pub mod reproduction;
+pub mod sauvegarde;
+pub mod stats;
+pub mod talent;
+
+pub use elevage::Elevage;
+pub use especes::{Espece, SpeciesSource};
+pub use pokemon::{Genre, Pokemon, TypePokemon};
+pub use reproduction::reproduction;
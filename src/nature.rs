@@ -0,0 +1,155 @@
+use rand::Rng;
+use std::fmt;
+
+use crate::stats::Statistique;
+
+// Les 25 natures: chacune augmente une statistique de 10% et en diminue une autre de 10%
+// (les natures neutres augmentent et diminuent la même, sans effet)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Nature {
+    Hardi,
+    Solo,
+    Brave,
+    Rigide,
+    Mauvais,
+    Assure,
+    Docile,
+    Relax,
+    Malin,
+    Lache,
+    Timide,
+    Presse,
+    Serieux,
+    Jovial,
+    Naif,
+    Modeste,
+    Doux,
+    Discret,
+    Pudique,
+    Foufou,
+    Calme,
+    Gentil,
+    Malpoli,
+    Prudent,
+    Bizarre,
+}
+
+impl Nature {
+    // Rangées dans l'ordre des jeux: la statistique augmentée change toutes les 5 natures
+    pub const TOUTES: [Nature; 25] = [
+        Nature::Hardi,
+        Nature::Solo,
+        Nature::Brave,
+        Nature::Rigide,
+        Nature::Mauvais,
+        Nature::Assure,
+        Nature::Docile,
+        Nature::Relax,
+        Nature::Malin,
+        Nature::Lache,
+        Nature::Timide,
+        Nature::Presse,
+        Nature::Serieux,
+        Nature::Jovial,
+        Nature::Naif,
+        Nature::Modeste,
+        Nature::Doux,
+        Nature::Discret,
+        Nature::Pudique,
+        Nature::Foufou,
+        Nature::Calme,
+        Nature::Gentil,
+        Nature::Malpoli,
+        Nature::Prudent,
+        Nature::Bizarre,
+    ];
+
+    // Ordre des statistiques utilisé par la table des natures
+    const ORDRE: [Statistique; 5] = [
+        Statistique::Attaque,
+        Statistique::Defense,
+        Statistique::Vitesse,
+        Statistique::AttaqueSpe,
+        Statistique::DefenseSpe,
+    ];
+
+    // Nature tirée au hasard
+    pub fn aleatoire() -> Self {
+        let mut rng = rand::thread_rng();
+        Self::TOUTES[rng.gen_range(0..Self::TOUTES.len())]
+    }
+
+    fn rang(&self) -> usize {
+        Self::TOUTES
+            .iter()
+            .position(|nature| nature == self)
+            .unwrap_or(0)
+    }
+
+    // Statistiques augmentée et diminuée (None pour une nature neutre)
+    pub fn modificateurs(&self) -> Option<(Statistique, Statistique)> {
+        let rang = self.rang();
+        let (hausse, baisse) = (Self::ORDRE[rang / 5], Self::ORDRE[rang % 5]);
+        if hausse == baisse {
+            None
+        } else {
+            Some((hausse, baisse))
+        }
+    }
+
+    // Appliquer le modificateur de la nature à une statistique (arrondi inférieur)
+    pub fn appliquer(&self, stat: Statistique, valeur: u32) -> u32 {
+        match self.modificateurs() {
+            Some((hausse, _)) if hausse == stat => valeur * 110 / 100,
+            Some((_, baisse)) if baisse == stat => valeur * 90 / 100,
+            _ => valeur,
+        }
+    }
+
+    // Retrouver une nature à partir de son nom affiché
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        Self::TOUTES
+            .into_iter()
+            .find(|nature| nature.to_string() == nom)
+    }
+
+    // Nature et effets, par exemple "Rigide (+Attaque, -Attaque Spé.)"
+    pub fn description(&self) -> String {
+        match self.modificateurs() {
+            Some((hausse, baisse)) => format!("{} (+{}, -{})", self, hausse, baisse),
+            None => format!("{} (neutre)", self),
+        }
+    }
+}
+
+impl fmt::Display for Nature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Nature::Hardi => write!(f, "Hardi"),
+            Nature::Solo => write!(f, "Solo"),
+            Nature::Brave => write!(f, "Brave"),
+            Nature::Rigide => write!(f, "Rigide"),
+            Nature::Mauvais => write!(f, "Mauvais"),
+            Nature::Assure => write!(f, "Assuré"),
+            Nature::Docile => write!(f, "Docile"),
+            Nature::Relax => write!(f, "Relax"),
+            Nature::Malin => write!(f, "Malin"),
+            Nature::Lache => write!(f, "Lâche"),
+            Nature::Timide => write!(f, "Timide"),
+            Nature::Presse => write!(f, "Pressé"),
+            Nature::Serieux => write!(f, "Sérieux"),
+            Nature::Jovial => write!(f, "Jovial"),
+            Nature::Naif => write!(f, "Naïf"),
+            Nature::Modeste => write!(f, "Modeste"),
+            Nature::Doux => write!(f, "Doux"),
+            Nature::Discret => write!(f, "Discret"),
+            Nature::Pudique => write!(f, "Pudique"),
+            Nature::Foufou => write!(f, "Foufou"),
+            Nature::Calme => write!(f, "Calme"),
+            Nature::Gentil => write!(f, "Gentil"),
+            Nature::Malpoli => write!(f, "Malpoli"),
+            Nature::Prudent => write!(f, "Prudent"),
+            Nature::Bizarre => write!(f, "Bizarre"),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::elevage::Elevage;
+use crate::stats::Statistique;
+
+// Nombre d'IV transmis lorsqu'un parent tient un Nœud Destin
+pub const IVS_NOEUD_DESTIN: usize = 5;
+
+// Objet tenu par un Pokémon; les objets de reproduction agissent à la pension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objet {
+    // Transmet la nature du parent qui la tient
+    PierreStase,
+    // Transmet 5 IV des parents au lieu du nombre prévu par les règles
+    NoeudDestin,
+    // Objets Pouvoir: transmettent l'IV d'une statistique du parent qui les tient
+    Pouvoir(Statistique),
+}
+
+impl Objet {
+    pub const TOUS: [Objet; 8] = [
+        Objet::PierreStase,
+        Objet::NoeudDestin,
+        Objet::Pouvoir(Statistique::Pv),
+        Objet::Pouvoir(Statistique::Attaque),
+        Objet::Pouvoir(Statistique::Defense),
+        Objet::Pouvoir(Statistique::AttaqueSpe),
+        Objet::Pouvoir(Statistique::DefenseSpe),
+        Objet::Pouvoir(Statistique::Vitesse),
+    ];
+
+    // Format de sauvegarde: le nom de l'objet ("Pierre Stase", "Bracelet Pouvoir"...)
+    pub fn depuis_nom(nom: &str) -> Option<Self> {
+        Self::TOUS
+            .into_iter()
+            .find(|objet| objet.to_string() == nom)
+    }
+
+    // Effet de l'objet lors d'une reproduction
+    pub fn effet(&self) -> String {
+        match self {
+            Objet::PierreStase => "transmet la nature du parent".to_string(),
+            Objet::NoeudDestin => format!("transmet {} IV des parents", IVS_NOEUD_DESTIN),
+            Objet::Pouvoir(stat) => format!("transmet l'IV en {} du parent", stat),
+        }
+    }
+}
+
+impl fmt::Display for Objet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Objet::PierreStase => write!(f, "Pierre Stase"),
+            Objet::NoeudDestin => write!(f, "Nœud Destin"),
+            Objet::Pouvoir(Statistique::Pv) => write!(f, "Poids Pouvoir"),
+            Objet::Pouvoir(Statistique::Attaque) => write!(f, "Bracelet Pouvoir"),
+            Objet::Pouvoir(Statistique::Defense) => write!(f, "Ceinture Pouvoir"),
+            Objet::Pouvoir(Statistique::AttaqueSpe) => write!(f, "Lentille Pouvoir"),
+            Objet::Pouvoir(Statistique::DefenseSpe) => write!(f, "Bandeau Pouvoir"),
+            Objet::Pouvoir(Statistique::Vitesse) => write!(f, "Chaîne Pouvoir"),
+        }
+    }
+}
+
+impl Elevage {
+    // Donner un objet à un Pokémon (None pour lui reprendre le sien); renvoie l'objet qu'il tenait
+    pub fn donner_objet(
+        &mut self,
+        id: u32,
+        objet: Option<Objet>,
+    ) -> Result<Option<Objet>, Box<dyn Error>> {
+        let pokemon = self
+            .pokemon_par_id_mut(id)
+            .ok_or_else(|| format!("Aucun Pokémon avec l'ID {}", id))?;
+        let ancien = std::mem::replace(&mut pokemon.objet, objet);
+        match (objet, ancien) {
+            (Some(objet), _) => println!("{} tient maintenant: {}.", pokemon.nom, objet),
+            (None, Some(ancien)) => println!("{} a été repris à {}.", ancien, pokemon.nom),
+            (None, None) => println!("{} ne tient aucun objet.", pokemon.nom),
+        }
+        Ok(ancien)
+    }
+}
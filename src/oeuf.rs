@@ -0,0 +1,82 @@
+use crate::elevage::Elevage;
+use crate::pokemon::Pokemon;
+
+// Nombre de pas d'un cycle d'éclosion
+pub const PAS_PAR_CYCLE: u32 = 256;
+
+// Cycles d'éclosion des espèces dont on ne connaît pas la valeur
+pub const CYCLES_ECLOSION_PAR_DEFAUT: u32 = 20;
+
+// Pas parcourus avec les œufs pendant une séance d'entraînement
+pub const PAS_PAR_SEANCE: u32 = 5 * PAS_PAR_CYCLE;
+
+// Œuf pondu à la pension, contenant le Pokémon qui en sortira
+#[derive(Debug, Clone)]
+pub struct Oeuf {
+    pub pokemon: Pokemon,
+    pub pas_restants: u32,
+}
+
+impl Oeuf {
+    // Œuf qui éclora après le nombre de cycles de son espèce
+    pub fn new(pokemon: Pokemon, cycles_eclosion: u32) -> Self {
+        Oeuf {
+            pokemon,
+            pas_restants: cycles_eclosion * PAS_PAR_CYCLE,
+        }
+    }
+
+    // Faire avancer l'œuf; renvoie vrai s'il est prêt à éclore
+    pub fn avancer(&mut self, pas: u32) -> bool {
+        self.pas_restants = self.pas_restants.saturating_sub(pas);
+        self.pas_restants == 0
+    }
+
+    // Afficher l'œuf (l'espèce reste une surprise)
+    pub fn afficher(&self) {
+        let message = match self.pas_restants {
+            0..=PAS_PAR_CYCLE => "Il bouge! Il va bientôt éclore!",
+            _ if self.pas_restants <= 5 * PAS_PAR_CYCLE => "Des bruits proviennent de l'intérieur.",
+            _ => "Il semble qu'il va falloir attendre longtemps.",
+        };
+        println!("Œuf: encore {} pas. {}", self.pas_restants, message);
+    }
+}
+
+impl Elevage {
+    // Afficher les œufs de l'élevage
+    pub fn afficher_oeufs(&self) {
+        if self.oeufs.is_empty() {
+            println!("Aucun œuf à la pension.");
+            return;
+        }
+
+        println!("\n===== ŒUFS =====");
+        for oeuf in &self.oeufs {
+            oeuf.afficher();
+        }
+    }
+
+    // Faire avancer tous les œufs; ceux qui éclosent rejoignent l'élevage (renvoie leurs identifiants)
+    pub fn faire_avancer_oeufs(&mut self, pas: u32) -> Vec<u32> {
+        let (eclos, restants): (Vec<Oeuf>, Vec<Oeuf>) = std::mem::take(&mut self.oeufs)
+            .into_iter()
+            .map(|mut oeuf| {
+                oeuf.avancer(pas);
+                oeuf
+            })
+            .partition(|oeuf| oeuf.pas_restants == 0);
+        self.oeufs = restants;
+
+        eclos
+            .into_iter()
+            .map(|oeuf| {
+                println!(
+                    "Oh? L'œuf éclot! {} ({}) est né!",
+                    oeuf.pokemon.nom, oeuf.pokemon.nom_espece
+                );
+                self.inserer(oeuf.pokemon)
+            })
+            .collect()
+    }
+}
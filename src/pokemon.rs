@@ -0,0 +1,637 @@
+use rand::Rng;
+use std::error::Error;
+use std::fmt;
+
+use crate::attaques::{ATTAQUES_MAX, AttaqueApprise, attaques_au_niveau};
+use crate::croissance::{CourbeCroissance, NIVEAU_MAX};
+use crate::especes::{Espece, SpeciesSource};
+use crate::evolution::{Declencheur, Evolution};
+use crate::groupe_oeuf::{GroupeOeuf, groupes_en_texte};
+use crate::nature::Nature;
+use crate::objet::Objet;
+use crate::regles::ReglesElevage;
+use crate::stats::{EV_MAX_PAR_STAT, EV_MAX_TOTAL, Statistique, Stats, calculer_stats};
+use crate::talent::{Talent, talent_apres_evolution, tirer_talent};
+
+// Statistiques de base des Pokémon créés sans espèce connue
+pub const STATS_DE_BASE_PAR_DEFAUT: u32 = 50;
+
+// Un Pokémon sur CHANCES_CHROMATIQUE est chromatique
+pub const CHANCES_CHROMATIQUE: u32 = 4096;
+
+// Origine des Pokémon obtenus dans cet élevage
+pub const ORIGINE_PAR_DEFAUT: &str = "Locale";
+
+// Partie 1: Définir les Pokémon
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypePokemon {
+    Feu,
+    Eau,
+    Plante,
+    Electrik,
+    Normal,
+    Psy,
+    Poison,
+    Sol,
+    Vol,
+    Combat,
+    Roche,
+    Insecte,
+    Spectre,
+    Glace,
+    Dragon,
+    Fee,
+    Acier,
+    Tenebres,
+}
+
+impl TypePokemon {
+    // Les 18 types, dans l'ordre du menu de sélection
+    pub const TOUS: [TypePokemon; 18] = [
+        TypePokemon::Feu,
+        TypePokemon::Eau,
+        TypePokemon::Plante,
+        TypePokemon::Electrik,
+        TypePokemon::Normal,
+        TypePokemon::Psy,
+        TypePokemon::Poison,
+        TypePokemon::Sol,
+        TypePokemon::Vol,
+        TypePokemon::Combat,
+        TypePokemon::Roche,
+        TypePokemon::Insecte,
+        TypePokemon::Spectre,
+        TypePokemon::Glace,
+        TypePokemon::Dragon,
+        TypePokemon::Fee,
+        TypePokemon::Acier,
+        TypePokemon::Tenebres,
+    ];
+}
+
+impl fmt::Display for TypePokemon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TypePokemon::Feu => write!(f, "Feu"),
+            TypePokemon::Eau => write!(f, "Eau"),
+            TypePokemon::Plante => write!(f, "Plante"),
+            TypePokemon::Electrik => write!(f, "Electrik"),
+            TypePokemon::Normal => write!(f, "Normal"),
+            TypePokemon::Psy => write!(f, "Psy"),
+            TypePokemon::Poison => write!(f, "Poison"),
+            TypePokemon::Sol => write!(f, "Sol"),
+            TypePokemon::Vol => write!(f, "Vol"),
+            TypePokemon::Combat => write!(f, "Combat"),
+            TypePokemon::Roche => write!(f, "Roche"),
+            TypePokemon::Insecte => write!(f, "Insecte"),
+            TypePokemon::Spectre => write!(f, "Spectre"),
+            TypePokemon::Glace => write!(f, "Glace"),
+            TypePokemon::Dragon => write!(f, "Dragon"),
+            TypePokemon::Fee => write!(f, "Fée"),
+            TypePokemon::Acier => write!(f, "Acier"),
+            TypePokemon::Tenebres => write!(f, "Ténèbres"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Genre {
+    Male,
+    Femelle,
+    // Pokémon asexués (Magnéti, Staross...)
+    Inconnu,
+}
+
+impl fmt::Display for Genre {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Genre::Male => write!(f, "Mâle"),
+            Genre::Femelle => write!(f, "Femelle"),
+            Genre::Inconnu => write!(f, "Inconnu"),
+        }
+    }
+}
+
+// Répartition des genres d'une espèce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepartitionGenres {
+    Asexuee,
+    // Chances d'être femelle, en huitièmes (0 = toujours mâle, 8 = toujours femelle)
+    Femelles(u8),
+}
+
+impl Default for RepartitionGenres {
+    fn default() -> Self {
+        RepartitionGenres::Femelles(4)
+    }
+}
+
+impl RepartitionGenres {
+    // Conversion depuis le `gender_rate` de la PokéAPI (-1 pour les espèces asexuées)
+    pub fn depuis_taux(taux: i32) -> Self {
+        match taux {
+            0..=8 => RepartitionGenres::Femelles(taux as u8),
+            _ => RepartitionGenres::Asexuee,
+        }
+    }
+
+    pub fn taux(&self) -> i32 {
+        match self {
+            RepartitionGenres::Asexuee => -1,
+            RepartitionGenres::Femelles(huitiemes) => i32::from(*huitiemes),
+        }
+    }
+
+    // Tirer le genre d'un Pokémon de l'espèce
+    pub fn tirer_genre(&self) -> Genre {
+        match self {
+            RepartitionGenres::Asexuee => Genre::Inconnu,
+            RepartitionGenres::Femelles(huitiemes) => {
+                if rand::thread_rng().gen_ratio(u32::from((*huitiemes).min(8)), 8) {
+                    Genre::Femelle
+                } else {
+                    Genre::Male
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for RepartitionGenres {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RepartitionGenres::Asexuee => write!(f, "Asexué"),
+            RepartitionGenres::Femelles(huitiemes) => write!(
+                f,
+                "{:.1}% mâles, {:.1}% femelles",
+                f64::from(8 - huitiemes.min(&8)) * 12.5,
+                f64::from(*huitiemes.min(&8)) * 12.5
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pokemon {
+    // Identifiant unique dans l'élevage (0 tant que le Pokémon n'y a pas été ajouté)
+    pub id: u32,
+    pub nom: String,
+    pub espece_id: Option<u32>,
+    pub nom_espece: String,
+    pub niveau: u32,
+    pub type_pokemon: TypePokemon,
+    pub type_secondaire: Option<TypePokemon>,
+    // Expérience totale accumulée depuis le niveau 1
+    pub experience: u32,
+    pub croissance: CourbeCroissance,
+    pub genre: Genre,
+    pub repartition_genres: RepartitionGenres,
+    // Parents (pour les Pokémon nés dans l'élevage) et génération (0 pour un fondateur)
+    pub mere_id: Option<u32>,
+    pub pere_id: Option<u32>,
+    pub generation: u32,
+    // Réduction des statistiques et des gains d'XP due à la consanguinité (0.0 = aucune)
+    pub malus_consanguinite: f64,
+    // Valeurs individuelles (0 à 31), tirées à la création ou héritées des parents
+    pub ivs: Stats,
+    // Statistiques de base de l'espèce et EV, qui donnent avec le niveau et les IV les statistiques réelles
+    pub stats_de_base: Stats,
+    pub evs: Stats,
+    pub stats: Stats,
+    pub nature: Nature,
+    // Objet tenu (Pierre Stase, Nœud Destin, objets Pouvoir...)
+    pub objet: Option<Objet>,
+    // Évolutions de l'espèce, et évolution déclenchée en attente de confirmation
+    pub evolutions: Vec<Evolution>,
+    pub evolution_en_attente: Option<Evolution>,
+    // Événements marquants de la vie du Pokémon (évolutions...)
+    pub historique: Vec<String>,
+    // Groupes Œuf de l'espèce (vide pour un Pokémon créé sans espèce connue)
+    pub groupes_oeuf: Vec<GroupeOeuf>,
+    // Pokémon chromatique (couleurs rares), et origine utilisée pour la méthode Masuda
+    pub chromatique: bool,
+    pub origine: String,
+    // Attaques connues (quatre au plus), attaques apprises par niveau et attaques à confirmer
+    pub attaques: Vec<String>,
+    pub apprentissage: Vec<AttaqueApprise>,
+    pub attaques_en_attente: Vec<String>,
+    // Attaques Œuf de l'espèce
+    pub attaques_oeuf: Vec<String>,
+    // Talent du Pokémon, choisi parmi les talents de son espèce
+    pub talent: Option<Talent>,
+    pub talents: Vec<Talent>,
+}
+
+// Partie 2: Fonctions et comportements
+impl Pokemon {
+    // Constructeur pour un nouveau Pokémon
+    pub fn new(nom: String, type_pokemon: TypePokemon, genre: Genre) -> Self {
+        let mut pokemon = Pokemon {
+            id: 0,
+            nom_espece: nom.clone(),
+            espece_id: None,
+            nom,
+            niveau: 1,
+            type_pokemon,
+            type_secondaire: None,
+            experience: 0,
+            croissance: CourbeCroissance::default(),
+            genre,
+            repartition_genres: RepartitionGenres::default(),
+            mere_id: None,
+            pere_id: None,
+            generation: 0,
+            malus_consanguinite: 0.0,
+            ivs: Stats::ivs_aleatoires(),
+            stats_de_base: Stats::uniformes(STATS_DE_BASE_PAR_DEFAUT),
+            evs: Stats::default(),
+            stats: Stats::default(),
+            nature: Nature::aleatoire(),
+            objet: None,
+            evolutions: Vec::new(),
+            evolution_en_attente: None,
+            historique: Vec::new(),
+            groupes_oeuf: Vec::new(),
+            // Tiré par l'élevage à l'arrivée du Pokémon, selon ses règles
+            chromatique: false,
+            origine: ORIGINE_PAR_DEFAUT.to_string(),
+            attaques: Vec::new(),
+            apprentissage: Vec::new(),
+            attaques_en_attente: Vec::new(),
+            attaques_oeuf: Vec::new(),
+            talent: None,
+            talents: Vec::new(),
+        };
+        pokemon.recalculer_stats();
+        pokemon
+    }
+
+    // Créer un Pokémon à partir des données d'une espèce
+    pub fn depuis_espece(espece: &Espece) -> Self {
+        // Obtenir les types primaire et secondaire du Pokémon
+        let pokemon_type = espece.types.first().cloned().unwrap_or(TypePokemon::Normal);
+        let type_secondaire = espece.types.get(1).cloned();
+
+        // Tirer le genre selon la répartition de l'espèce
+        let genre = espece.repartition_genres.tirer_genre();
+
+        let mut pokemon = Pokemon {
+            espece_id: Some(espece.id),
+            type_secondaire,
+            stats_de_base: espece.stats_de_base,
+            croissance: espece.croissance,
+            evolutions: espece.evolutions.clone(),
+            groupes_oeuf: espece.groupes_oeuf.clone(),
+            repartition_genres: espece.repartition_genres,
+            attaques: attaques_au_niveau(&espece.apprentissage, 1),
+            apprentissage: espece.apprentissage.clone(),
+            attaques_oeuf: espece.attaques_oeuf.clone(),
+            talent: tirer_talent(&espece.talents),
+            talents: espece.talents.clone(),
+            ..Pokemon::new(espece.nom.clone(), pokemon_type, genre)
+        };
+        pokemon.recalculer_stats();
+        pokemon
+    }
+
+    // Récupérer un Pokémon depuis la source d'espèces configurée
+    pub fn from_api(source: &dyn SpeciesSource, pokemon_id: u32) -> Result<Self, Box<dyn Error>> {
+        let espece = source.espece(pokemon_id)?;
+        Ok(Self::depuis_espece(&espece))
+    }
+
+    // Génère un Pokémon aléatoire parmi les espèces de la source
+    pub fn generer_aleatoire(
+        source: &dyn SpeciesSource,
+        nom: Option<String>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let identifiants = source.identifiants();
+        if identifiants.is_empty() {
+            return Err("Aucune espèce disponible".into());
+        }
+        let mut rng = rand::thread_rng();
+        let pokemon_id = identifiants[rng.gen_range(0..identifiants.len())];
+
+        // Si un surnom est fourni, on l'utilise, sinon on prend le nom de l'espèce
+        let pokemon = Self::from_api(source, pokemon_id)?;
+
+        if let Some(custom_name) = nom {
+            Ok(Pokemon {
+                nom: custom_name,
+                ..pokemon
+            })
+        } else {
+            Ok(pokemon)
+        }
+    }
+
+    // Gagner de l'expérience
+    pub fn gagner_xp(&mut self, points: u32) {
+        let points = (f64::from(points) * (1.0 - self.malus_consanguinite)).round() as u32;
+        let experience_max = self.croissance.experience_pour_niveau(NIVEAU_MAX);
+        self.experience = self.experience.saturating_add(points).min(experience_max);
+
+        // Vérifier si le Pokémon peut monter de niveau (selon la courbe de son espèce)
+        let nouveau_niveau = self.croissance.niveau_pour_experience(self.experience);
+        if nouveau_niveau > self.niveau {
+            let ancien_niveau = self.niveau;
+            self.niveau = nouveau_niveau;
+            self.recalculer_stats();
+            println!("{} monte au niveau {}!", self.nom, self.niveau);
+
+            // Attaques apprises aux niveaux franchis
+            let nouvelles: Vec<String> = self
+                .apprentissage
+                .iter()
+                .filter(|attaque| {
+                    attaque.niveau > ancien_niveau && attaque.niveau <= nouveau_niveau
+                })
+                .map(|attaque| attaque.nom.clone())
+                .collect();
+            for attaque in nouvelles {
+                self.apprendre_attaque(attaque);
+            }
+
+            if let Some(evolution) = self.evolution_par_niveau() {
+                println!("Quoi? {} évolue en {}!", self.nom, evolution.nom_espece);
+                self.evolution_en_attente = Some(evolution);
+            }
+        }
+    }
+
+    // Apprendre une attaque s'il reste de la place, sinon la mettre en attente d'une attaque à oublier
+    pub fn apprendre_attaque(&mut self, attaque: String) {
+        if self.attaques.contains(&attaque) || self.attaques_en_attente.contains(&attaque) {
+            return;
+        }
+        if self.attaques.len() < ATTAQUES_MAX {
+            println!("{} apprend {}!", self.nom, attaque);
+            self.attaques.push(attaque);
+        } else {
+            println!(
+                "{} veut apprendre {}, mais connaît déjà {} attaques.",
+                self.nom, attaque, ATTAQUES_MAX
+            );
+            self.attaques_en_attente.push(attaque);
+        }
+    }
+
+    // Première évolution par niveau dont le seuil est atteint
+    pub fn evolution_par_niveau(&self) -> Option<Evolution> {
+        self.evolutions
+            .iter()
+            .find(|evolution| {
+                matches!(evolution.declencheur, Declencheur::Niveau(seuil) if self.niveau >= seuil)
+            })
+            .cloned()
+    }
+
+    // Évoluer vers une nouvelle espèce (le surnom est conservé s'il diffère du nom de l'espèce)
+    pub fn evoluer(&mut self, espece: &Espece) {
+        let ancienne_espece = self.nom_espece.clone();
+        if self.nom == self.nom_espece {
+            self.nom = espece.nom.clone();
+        }
+        self.espece_id = Some(espece.id);
+        self.nom_espece = espece.nom.clone();
+        self.type_pokemon = espece.types.first().cloned().unwrap_or(TypePokemon::Normal);
+        self.type_secondaire = espece.types.get(1).cloned();
+        self.stats_de_base = espece.stats_de_base;
+        self.croissance = espece.croissance;
+        self.evolutions = espece.evolutions.clone();
+        self.groupes_oeuf = espece.groupes_oeuf.clone();
+        self.repartition_genres = espece.repartition_genres;
+        self.apprentissage = espece.apprentissage.clone();
+        self.attaques_oeuf = espece.attaques_oeuf.clone();
+        self.talent = talent_apres_evolution(self.talent.as_ref(), &self.talents, &espece.talents);
+        self.talents = espece.talents.clone();
+        self.evolution_en_attente = None;
+        self.recalculer_stats();
+
+        self.historique.push(format!(
+            "Niveau {}: a évolué de {} en {}",
+            self.niveau, ancienne_espece, espece.nom
+        ));
+        println!(
+            "Félicitations! {} a évolué en {}!",
+            ancienne_espece, espece.nom
+        );
+    }
+
+    // Progression vers le niveau suivant: (XP acquise dans le niveau, XP nécessaire), None au niveau maximal
+    pub fn progression(&self) -> Option<(u32, u32)> {
+        if self.niveau >= NIVEAU_MAX {
+            return None;
+        }
+        let debut = self.croissance.experience_pour_niveau(self.niveau);
+        let fin = self.croissance.experience_pour_niveau(self.niveau + 1);
+        Some((self.experience.saturating_sub(debut), fin - debut))
+    }
+
+    // Gagner des EV dans une statistique, dans la limite des plafonds; renvoie les EV réellement gagnés
+    pub fn gagner_evs(&mut self, stat: Statistique, points: u32) -> u32 {
+        let actuel = self.evs.valeur(stat);
+        let gagnes = points
+            .min(EV_MAX_PAR_STAT.saturating_sub(actuel))
+            .min(EV_MAX_TOTAL.saturating_sub(self.evs.total()));
+        if gagnes > 0 {
+            self.evs.definir(stat, actuel + gagnes);
+            self.recalculer_stats();
+        }
+        gagnes
+    }
+
+    // Changer directement le niveau du Pokémon (il connaît alors les dernières attaques de ce niveau)
+    pub fn fixer_niveau(&mut self, niveau: u32) {
+        self.niveau = niveau.clamp(1, NIVEAU_MAX);
+        self.experience = self.croissance.experience_pour_niveau(self.niveau);
+        if !self.apprentissage.is_empty() {
+            self.attaques = attaques_au_niveau(&self.apprentissage, self.niveau);
+            self.attaques_en_attente.clear();
+        }
+        self.recalculer_stats();
+    }
+
+    // Mettre à jour les statistiques réelles à partir des statistiques de base, du niveau, des IV et des EV
+    pub fn recalculer_stats(&mut self) {
+        self.stats = calculer_stats(
+            &self.stats_de_base,
+            &self.ivs,
+            &self.evs,
+            self.niveau,
+            self.nature,
+        );
+        // Un petit consanguin a des statistiques réduites du coefficient de consanguinité
+        if self.malus_consanguinite > 0.0 {
+            for stat in Statistique::TOUTES {
+                let valeur = f64::from(self.stats.valeur(stat)) * (1.0 - self.malus_consanguinite);
+                self.stats.definir(stat, (valeur.round() as u32).max(1));
+            }
+        }
+    }
+
+    // Types du Pokémon, le type primaire en premier
+    pub fn types(&self) -> Vec<&TypePokemon> {
+        std::iter::once(&self.type_pokemon)
+            .chain(self.type_secondaire.as_ref())
+            .collect()
+    }
+
+    // Types sous la forme "Plante/Poison"
+    pub fn libelle_types(&self) -> String {
+        match &self.type_secondaire {
+            Some(secondaire) => format!("{}/{}", self.type_pokemon, secondaire),
+            None => self.type_pokemon.to_string(),
+        }
+    }
+
+    // Vérifier si les deux Pokémon ont au moins un type en commun
+    pub fn partage_un_type_avec(&self, autre: &Pokemon) -> bool {
+        self.types().iter().any(|t| autre.types().contains(t))
+    }
+
+    // Données que la source n'a pas fournies pour l'espèce du Pokémon (les données embarquées
+    // ne couvrent pas toutes les espèces); vide pour un Pokémon créé sans espèce
+    pub fn donnees_manquantes(&self) -> Vec<&'static str> {
+        let mut manquantes = Vec::new();
+        if self.espece_id.is_none() {
+            return manquantes;
+        }
+        if self.apprentissage.is_empty() {
+            manquantes.push("attaques");
+        }
+        if self.talents.is_empty() {
+            manquantes.push("talents");
+        }
+        manquantes
+    }
+
+    // Espèce sous la forme "Pikachu (#25)"
+    pub fn libelle_espece(&self) -> String {
+        match self.espece_id {
+            Some(id) => format!("{} (#{})", self.nom_espece, id),
+            None => self.nom_espece.clone(),
+        }
+    }
+
+    // Afficher les informations du Pokémon
+    pub fn afficher(&self) {
+        if self.chromatique {
+            println!("★ CHROMATIQUE ★");
+        }
+        println!("Nom: {}", self.nom);
+        println!("Espèce: {}", self.libelle_espece());
+        println!("Type: {}", self.libelle_types());
+        println!("Niveau: {}", self.niveau);
+        match self.progression() {
+            Some((acquise, necessaire)) => println!(
+                "XP: {}/{} (total: {})",
+                acquise, necessaire, self.experience
+            ),
+            None => println!("XP: niveau maximal (total: {})", self.experience),
+        }
+        println!("Croissance: {}", self.croissance);
+        if !self.evolutions.is_empty() {
+            let evolutions: Vec<String> = self
+                .evolutions
+                .iter()
+                .map(|evolution| format!("{} ({})", evolution.nom_espece, evolution.declencheur))
+                .collect();
+            println!("Évolutions: {}", evolutions.join(", "));
+        }
+        if !self.groupes_oeuf.is_empty() {
+            println!("Groupes Œuf: {}", groupes_en_texte(&self.groupes_oeuf));
+        }
+        println!(
+            "Genre: {} (espèce: {})",
+            self.genre, self.repartition_genres
+        );
+        println!("Origine: {}", self.origine);
+        if let Some(talent) = &self.talent {
+            println!("Talent: {}", talent);
+        } else if self.donnees_manquantes().contains(&"talents") {
+            println!("Talent: inconnu (pas de données pour cette espèce)");
+        }
+        println!("Nature: {}", self.nature.description());
+        if let Some(objet) = self.objet {
+            println!("Objet: {} ({})", objet, objet.effet());
+        }
+        if self.attaques.is_empty() && self.donnees_manquantes().contains(&"attaques") {
+            println!("Attaques: aucune (pas de données pour cette espèce)");
+        } else if self.attaques.is_empty() {
+            println!("Attaques: aucune");
+        } else {
+            println!("Attaques: {}", self.attaques.join(", "));
+        }
+        println!("Stats: {}", self.stats);
+        println!("IV: {}", self.ivs);
+        println!(
+            "EV: {} (total {}/{})",
+            self.evs,
+            self.evs.total(),
+            EV_MAX_TOTAL
+        );
+        if self.malus_consanguinite > 0.0 {
+            println!(
+                "Malus de consanguinité: -{:.0}% d'XP et de statistiques",
+                self.malus_consanguinite * 100.0
+            );
+        }
+        for evenement in &self.historique {
+            println!("Historique: {}", evenement);
+        }
+        println!("------------------------");
+    }
+
+    // Métamorph peut se reproduire avec tout Pokémon qui n'est ni Métamorph ni du groupe Inconnu
+    pub fn est_metamorph(&self) -> bool {
+        self.groupes_oeuf.contains(&GroupeOeuf::Metamorph)
+    }
+
+    // Vérifier si les deux Pokémon ont au moins un groupe Œuf en commun
+    // (à défaut de groupes connus, par exemple pour un Pokémon créé à la main, un type en commun)
+    pub fn partage_un_groupe_oeuf_avec(&self, autre: &Pokemon) -> bool {
+        if self.groupes_oeuf.is_empty() || autre.groupes_oeuf.is_empty() {
+            return self.partage_un_type_avec(autre);
+        }
+        self.groupes_oeuf
+            .iter()
+            .any(|groupe| autre.groupes_oeuf.contains(groupe))
+    }
+
+    // Vérifier si ce Pokémon peut se reproduire avec un autre
+    pub fn peut_se_reproduire_avec(&self, autre: &Pokemon) -> bool {
+        self.peut_se_reproduire_avec_regles(autre, &ReglesElevage::default())
+    }
+
+    // Compatibilité suivant les règles d'un élevage (niveau minimum...)
+    pub fn peut_se_reproduire_avec_regles(&self, autre: &Pokemon, regles: &ReglesElevage) -> bool {
+        // Aucun des deux dans le groupe Inconnu, niveau suffisant
+        if self.groupes_oeuf.contains(&GroupeOeuf::Inconnu)
+            || autre.groupes_oeuf.contains(&GroupeOeuf::Inconnu)
+            || self.niveau < regles.niveau_minimum
+            || autre.niveau < regles.niveau_minimum
+        {
+            return false;
+        }
+
+        // Métamorph s'accouple avec n'importe quel autre Pokémon, mais pas avec un autre Métamorph
+        match (self.est_metamorph(), autre.est_metamorph()) {
+            (true, true) => false,
+            (true, false) | (false, true) => true,
+            // Les Pokémon asexués ne se reproduisent qu'avec Métamorph
+            (false, false) => {
+                self.partage_un_groupe_oeuf_avec(autre)
+                    && self.genre != Genre::Inconnu
+                    && autre.genre != Genre::Inconnu
+                    && self.genre != autre.genre
+            }
+        }
+    }
+}
+
+// Tirer au sort un Pokémon chromatique: `multiplicateur` chances sur `chances`
+pub fn tirer_chromatique(chances: u32, multiplicateur: u32) -> bool {
+    let chances = chances.max(1);
+    rand::thread_rng().gen_ratio(multiplicateur.clamp(1, chances), chances)
+}
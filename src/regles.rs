@@ -0,0 +1,81 @@
+use std::fmt;
+
+use crate::pokemon::CHANCES_CHROMATIQUE;
+use crate::talent::CHANCES_TALENT_CACHE;
+
+// Conduite à tenir lorsque deux proches parents tentent de se reproduire
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PolitiqueConsanguinite {
+    // Prévenir l'éleveur mais laisser faire
+    Avertir,
+    // Le petit est plus faible (statistiques et gain d'XP réduits du coefficient de consanguinité)
+    Penaliser,
+    // Refuser la reproduction
+    Interdire,
+}
+
+impl fmt::Display for PolitiqueConsanguinite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolitiqueConsanguinite::Avertir => write!(f, "Avertir"),
+            PolitiqueConsanguinite::Penaliser => write!(f, "Pénaliser"),
+            PolitiqueConsanguinite::Interdire => write!(f, "Interdire"),
+        }
+    }
+}
+
+// Règles de l'élevage, modifiables par l'éleveur
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReglesElevage {
+    pub politique_consanguinite: PolitiqueConsanguinite,
+    // Coefficient à partir duquel la politique s'applique (0.125 = demi-frères)
+    pub seuil_consanguinite: f64,
+    // Nombre d'IV transmis par les parents lors d'une reproduction (0 à 6)
+    pub ivs_herites: usize,
+    // Niveau minimum des deux parents pour se reproduire
+    pub niveau_minimum: u32,
+    // Un petit sur `chances_chromatique` est chromatique, `multiplicateur_masuda` fois plus
+    // si ses parents ont des origines différentes
+    pub chances_chromatique: u32,
+    pub multiplicateur_masuda: u32,
+    // Pourcentage de chances qu'une mère transmette son talent caché
+    pub chances_talent_cache: u32,
+}
+
+impl Default for ReglesElevage {
+    fn default() -> Self {
+        ReglesElevage {
+            politique_consanguinite: PolitiqueConsanguinite::Avertir,
+            seuil_consanguinite: 0.125,
+            ivs_herites: 3,
+            niveau_minimum: 10,
+            chances_chromatique: CHANCES_CHROMATIQUE,
+            multiplicateur_masuda: 6,
+            chances_talent_cache: CHANCES_TALENT_CACHE,
+        }
+    }
+}
+
+impl ReglesElevage {
+    // Afficher les règles actuelles
+    pub fn afficher(&self) {
+        println!(
+            "Politique de consanguinité: {}",
+            self.politique_consanguinite
+        );
+        println!(
+            "Seuil de consanguinité: {:.2}%",
+            self.seuil_consanguinite * 100.0
+        );
+        println!("IV transmis par les parents: {}", self.ivs_herites);
+        println!("Niveau minimum pour se reproduire: {}", self.niveau_minimum);
+        println!(
+            "Chances d'obtenir un chromatique: 1/{} (x{} pour des parents d'origines différentes)",
+            self.chances_chromatique, self.multiplicateur_masuda
+        );
+        println!(
+            "Transmission du talent caché par la mère: {}%",
+            self.chances_talent_cache
+        );
+    }
+}
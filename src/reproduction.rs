@@ -0,0 +1,208 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::attaques::{ATTAQUES_MAX, attaques_au_niveau};
+use crate::nature::Nature;
+use crate::objet::{IVS_NOEUD_DESTIN, Objet};
+use crate::pokemon::{Genre, ORIGINE_PAR_DEFAUT, Pokemon, tirer_chromatique};
+use crate::regles::ReglesElevage;
+use crate::stats::{Statistique, Stats};
+use crate::talent::{Talent, talent_cache, tirer_talent};
+
+// Partie 3: Fonction de reproduction
+pub fn reproduction(pokemon1: &Pokemon, pokemon2: &Pokemon) -> Option<Pokemon> {
+    reproduction_avec_regles(pokemon1, pokemon2, &ReglesElevage::default())
+}
+
+// Reproduction suivant les règles d'un élevage (nombre d'IV transmis...)
+pub fn reproduction_avec_regles(
+    pokemon1: &Pokemon,
+    pokemon2: &Pokemon,
+    regles: &ReglesElevage,
+) -> Option<Pokemon> {
+    if pokemon1.peut_se_reproduire_avec_regles(pokemon2, regles) {
+        let mut rng = rand::thread_rng();
+
+        // Possibilité de donner un nom aléatoire au lieu de "Mystère"
+        let nom = if rng.gen_bool(0.7) {
+            "Mystère".to_string()
+        } else {
+            let noms = [
+                "Bébé", "Junior", "Mini", "Petit", "Toudou", "Poupon", "Mignon", "Doux",
+            ];
+            noms[rng.gen_range(0..noms.len())].to_string()
+        };
+
+        // Nouveau Pokémon hérite de l'espèce (et donc des types) de la mère, ou du partenaire de Métamorph
+        let mere = mere(pokemon1, pokemon2);
+        let pere = if std::ptr::eq(mere, pokemon1) {
+            pokemon2
+        } else {
+            pokemon1
+        };
+        // Méthode Masuda: plus de chances d'obtenir un chromatique avec des parents d'origines différentes
+        let multiplicateur = if mere.origine != pere.origine {
+            regles.multiplicateur_masuda
+        } else {
+            1
+        };
+
+        // Objets de reproduction tenus par les parents
+        for parent in [mere, pere] {
+            if let Some(objet) = parent.objet {
+                println!("{} tient {}: {}.", parent.nom, objet, objet.effet());
+            }
+        }
+
+        // Genre tiré selon la répartition de l'espèce du petit
+        let genre = mere.repartition_genres.tirer_genre();
+        let mut bebe = Pokemon {
+            id: 0,
+            nom,
+            espece_id: mere.espece_id,
+            nom_espece: mere.nom_espece.clone(),
+            niveau: 1,
+            type_pokemon: mere.type_pokemon.clone(),
+            type_secondaire: mere.type_secondaire.clone(),
+            experience: 0,
+            croissance: mere.croissance,
+            genre,
+            repartition_genres: mere.repartition_genres,
+            mere_id: Some(mere.id),
+            pere_id: Some(pere.id),
+            generation: mere.generation.max(pere.generation) + 1,
+            malus_consanguinite: 0.0,
+            ivs: heriter_ivs(mere, pere, regles.ivs_herites),
+            stats_de_base: mere.stats_de_base,
+            evs: Stats::default(),
+            stats: Stats::default(),
+            nature: heriter_nature(mere, pere),
+            objet: None,
+            evolutions: mere.evolutions.clone(),
+            evolution_en_attente: None,
+            historique: Vec::new(),
+            groupes_oeuf: mere.groupes_oeuf.clone(),
+            chromatique: tirer_chromatique(regles.chances_chromatique, multiplicateur),
+            origine: ORIGINE_PAR_DEFAUT.to_string(),
+            attaques: attaques_au_niveau(&mere.apprentissage, 1),
+            apprentissage: mere.apprentissage.clone(),
+            attaques_en_attente: Vec::new(),
+            attaques_oeuf: mere.attaques_oeuf.clone(),
+            talent: heriter_talent(mere, regles.chances_talent_cache),
+            talents: mere.talents.clone(),
+        };
+        bebe.recalculer_stats();
+
+        // Rapport des attaques Œuf transmises par le père
+        let heritees = heriter_attaques_oeuf(&mut bebe, pere);
+        if !heritees.is_empty() {
+            let rapport = format!(
+                "Attaques Œuf héritées de {}: {}",
+                pere.nom,
+                heritees.join(", ")
+            );
+            println!("{}", rapport);
+            bebe.historique.push(rapport);
+        }
+        Some(bebe)
+    } else {
+        None
+    }
+}
+
+// Parent femelle d'un couple (le premier si aucun n'est femelle); avec Métamorph, c'est l'autre parent
+pub fn mere<'a>(pokemon1: &'a Pokemon, pokemon2: &'a Pokemon) -> &'a Pokemon {
+    if pokemon1.est_metamorph() != pokemon2.est_metamorph() {
+        if pokemon1.est_metamorph() {
+            pokemon2
+        } else {
+            pokemon1
+        }
+    } else if pokemon2.genre == Genre::Femelle && pokemon1.genre != Genre::Femelle {
+        pokemon2
+    } else {
+        pokemon1
+    }
+}
+
+// Talent du petit: une mère au talent caché le transmet avec `chances` % de chances,
+// sinon le petit reçoit l'un des talents ordinaires de l'espèce
+pub fn heriter_talent(mere: &Pokemon, chances: u32) -> Option<Talent> {
+    let mut rng = rand::thread_rng();
+    let cache = mere.talent.as_ref().is_some_and(|talent| talent.cache);
+    if cache && rng.gen_ratio(chances.min(100), 100) {
+        return talent_cache(&mere.talents).or_else(|| mere.talent.clone());
+    }
+    tirer_talent(&mere.talents)
+}
+
+// Apprendre au petit les attaques Œuf de son espèce que connaît le père (elles remplacent
+// les plus anciennes attaques s'il en connaît déjà quatre); renvoie les attaques transmises
+pub fn heriter_attaques_oeuf(bebe: &mut Pokemon, pere: &Pokemon) -> Vec<String> {
+    let heritees: Vec<String> = pere
+        .attaques
+        .iter()
+        .filter(|attaque| bebe.attaques_oeuf.contains(attaque) && !bebe.attaques.contains(attaque))
+        .cloned()
+        .collect();
+    for attaque in &heritees {
+        if bebe.attaques.len() >= ATTAQUES_MAX {
+            bebe.attaques.remove(0);
+        }
+        bebe.attaques.push(attaque.clone());
+    }
+    heritees
+}
+
+// Transmettre `nombre` IV choisis au hasard, chacun venant d'un parent au hasard, les autres étant tirés
+// (5 IV avec un Nœud Destin; un objet Pouvoir impose l'IV transmis par le parent qui le tient)
+pub fn heriter_ivs(pokemon1: &Pokemon, pokemon2: &Pokemon, nombre: usize) -> Stats {
+    let mut rng = rand::thread_rng();
+    let mut ivs = Stats::ivs_aleatoires();
+    let parents = [pokemon1, pokemon2];
+    let mut nombre = if parents
+        .iter()
+        .any(|parent| parent.objet == Some(Objet::NoeudDestin))
+    {
+        nombre.max(IVS_NOEUD_DESTIN)
+    } else {
+        nombre
+    };
+
+    // Un seul objet Pouvoir agit, au hasard si les deux parents en tiennent
+    let pouvoirs: Vec<(&Pokemon, Statistique)> = parents
+        .into_iter()
+        .filter_map(|parent| match parent.objet {
+            Some(Objet::Pouvoir(stat)) => Some((parent, stat)),
+            _ => None,
+        })
+        .collect();
+    let mut restantes = Statistique::TOUTES.to_vec();
+    if let Some((parent, stat)) = pouvoirs.choose(&mut rng) {
+        ivs.definir(*stat, parent.ivs.valeur(*stat));
+        restantes.retain(|restante| restante != stat);
+        nombre = nombre.saturating_sub(1);
+    }
+
+    for stat in restantes.choose_multiple(&mut rng, nombre) {
+        let parent = if rng.gen_bool(0.5) {
+            pokemon1
+        } else {
+            pokemon2
+        };
+        ivs.definir(*stat, parent.ivs.valeur(*stat));
+    }
+    ivs
+}
+
+// Nature d'un parent qui tient une Pierre Stase (au hasard si les deux en tiennent une), sinon tirée au hasard
+pub fn heriter_nature(pokemon1: &Pokemon, pokemon2: &Pokemon) -> Nature {
+    let transmetteurs: Vec<&Pokemon> = [pokemon1, pokemon2]
+        .into_iter()
+        .filter(|parent| parent.objet == Some(Objet::PierreStase))
+        .collect();
+    match transmetteurs.choose(&mut rand::thread_rng()) {
+        Some(parent) => parent.nature,
+        None => Nature::aleatoire(),
+    }
+}
@@ -0,0 +1,485 @@
+use std::fs::File;
+use std::io::{self, Read, Write};
+
+use crate::attaques::{apprentissage_depuis_texte, apprentissage_en_texte, attaques_au_niveau};
+use crate::croissance::CourbeCroissance;
+use crate::elevage::Elevage;
+use crate::evolution::{Evolution, evolutions_depuis_texte, evolutions_en_texte};
+use crate::genealogie::Filiation;
+use crate::groupe_oeuf::{groupes_depuis_texte, groupes_en_texte};
+use crate::nature::Nature;
+use crate::objet::Objet;
+use crate::oeuf::Oeuf;
+use crate::pokemon::{
+    Genre, ORIGINE_PAR_DEFAUT, Pokemon, RepartitionGenres, STATS_DE_BASE_PAR_DEFAUT, TypePokemon,
+};
+use crate::stats::Stats;
+use crate::talent::{Talent, talents_depuis_texte, talents_en_texte, tirer_talent};
+
+// Début des lignes décrivant un œuf
+const MARQUEUR_OEUF: &str = "@oeuf|";
+// Ligne donnant le prochain identifiant à attribuer
+const MARQUEUR_PROCHAIN_ID: &str = "@prochain_id|";
+// Début des lignes décrivant la filiation d'un Pokémon relâché
+const MARQUEUR_RELACHE: &str = "@relache|";
+
+impl Elevage {
+    // Bonus: Sauvegarder l'élevage dans un fichier
+    pub fn sauvegarder(&self, fichier: &str) -> Result<(), io::Error> {
+        let mut file = File::create(fichier)?;
+
+        writeln!(file, "{}{}", MARQUEUR_PROCHAIN_ID, self.prochain_id())?;
+        for pokemon in &self.pokemon {
+            writeln!(file, "{}", ligne_pokemon(pokemon))?;
+        }
+        for filiation in &self.relaches {
+            writeln!(
+                file,
+                "{}{}|{}|{}|{}",
+                MARQUEUR_RELACHE,
+                filiation.id,
+                filiation
+                    .mere_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_default(),
+                filiation
+                    .pere_id
+                    .map(|id| id.to_string())
+                    .unwrap_or_default(),
+                filiation.generation
+            )?;
+        }
+        for oeuf in &self.oeufs {
+            writeln!(
+                file,
+                "{}{}|{}",
+                MARQUEUR_OEUF,
+                oeuf.pas_restants,
+                ligne_pokemon(&oeuf.pokemon)
+            )?;
+        }
+
+        println!("Élevage sauvegardé dans '{}'!", fichier);
+        Ok(())
+    }
+
+    // Bonus: Charger l'élevage depuis un fichier (remplace les Pokémon actuels)
+    pub fn charger(&mut self, fichier: &str) -> Result<(), io::Error> {
+        let mut pokemons = Vec::new();
+        let mut oeufs = Vec::new();
+        let mut prochain_id = None;
+        let mut relaches = Vec::new();
+        let mut file = File::open(fichier)?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+
+        for line in contents.lines() {
+            if let Some(id) = line.strip_prefix(MARQUEUR_PROCHAIN_ID) {
+                prochain_id = id.parse::<u32>().ok();
+                continue;
+            }
+            if let Some(reste) = line.strip_prefix(MARQUEUR_RELACHE) {
+                relaches.extend(lire_filiation(reste));
+                continue;
+            }
+            // Les œufs sont enregistrés comme le Pokémon qu'ils contiendront, précédés des pas restants
+            if let Some(reste) = line.strip_prefix(MARQUEUR_OEUF) {
+                if let Some((pas, ligne)) = reste.split_once('|')
+                    && let Some(pokemon) = self.lire_pokemon(ligne)
+                {
+                    oeufs.push(Oeuf {
+                        pokemon,
+                        pas_restants: pas.parse().unwrap_or(0),
+                    });
+                }
+            } else if let Some(pokemon) = self.lire_pokemon(line) {
+                pokemons.push(pokemon);
+            }
+        }
+
+        // Les œufs et les relâchés d'abord: leurs identifiants ne doivent pas être réattribués
+        self.oeufs = oeufs;
+        self.relaches = relaches;
+        self.remplacer_pokemon(pokemons, prochain_id);
+        println!("Élevage chargé depuis '{}'!", fichier);
+        Ok(())
+    }
+
+    // Lire un Pokémon depuis une ligne de sauvegarde
+    fn lire_pokemon(&self, line: &str) -> Option<Pokemon> {
+        let parts: Vec<&str> = line.split('|').collect();
+        // Les anciennes sauvegardes n'ont que les 5 premières colonnes
+        if parts.len() < 5 {
+            return None;
+        }
+
+        // Convertir les données
+        let nom = desechapper(parts[0]);
+        let niveau = parts[1].parse::<u32>().unwrap_or(1);
+
+        // Convertir les types ("Plante" ou "Plante/Poison")
+        let (type_pokemon, type_secondaire) = match parts[2].split_once('/') {
+            Some((primaire, secondaire)) => {
+                (convertir_type(primaire), Some(convertir_type(secondaire)))
+            }
+            None => (convertir_type(parts[2]), None),
+        };
+
+        let experience = parts[3].parse::<u32>().unwrap_or(0);
+
+        // Convertir le genre
+        let genre = match parts[4] {
+            "Mâle" => Genre::Male,
+            "Femelle" => Genre::Femelle,
+            "Inconnu" => Genre::Inconnu,
+            _ => Genre::Male, // Par défaut
+        };
+
+        // Espèce (le surnom sert d'espèce pour les anciennes sauvegardes)
+        let espece_id = parts.get(5).and_then(|id| id.parse::<u32>().ok());
+        let nom_espece = parts
+            .get(6)
+            .map(|espece| desechapper(espece))
+            .unwrap_or_else(|| nom.clone());
+
+        // Identifiant (un nouveau est attribué s'il manque)
+        let id = parts
+            .get(7)
+            .and_then(|id| id.parse::<u32>().ok())
+            .unwrap_or(0);
+
+        // Parents et génération
+        let mere_id = parts.get(8).and_then(|id| id.parse::<u32>().ok());
+        let pere_id = parts.get(9).and_then(|id| id.parse::<u32>().ok());
+        let generation = parts
+            .get(10)
+            .and_then(|generation| generation.parse::<u32>().ok())
+            .unwrap_or(0);
+
+        let malus_consanguinite = parts
+            .get(11)
+            .and_then(|malus| malus.parse::<f64>().ok())
+            .unwrap_or(0.0);
+
+        // IV (tirés au hasard pour les sauvegardes qui n'en ont pas)
+        let ivs = parts
+            .get(12)
+            .and_then(|ivs| Stats::depuis_texte(ivs))
+            .unwrap_or_else(Stats::ivs_aleatoires);
+
+        let stats_de_base = parts
+            .get(13)
+            .and_then(|stats| Stats::depuis_texte(stats))
+            .unwrap_or(Stats::uniformes(STATS_DE_BASE_PAR_DEFAUT));
+        let evs = parts
+            .get(14)
+            .and_then(|evs| Stats::depuis_texte(evs))
+            .unwrap_or_default();
+
+        // Nature (tirée au hasard si elle manque)
+        let nature = parts
+            .get(15)
+            .and_then(|nature| Nature::depuis_nom(nature))
+            .unwrap_or_else(Nature::aleatoire);
+        // Objet tenu; les anciennes sauvegardes notaient "true" pour un Pokémon qui transmettait sa nature
+        let objet = match parts.get(16) {
+            Some(&"true") => Some(Objet::PierreStase),
+            Some(objet) => Objet::depuis_nom(objet),
+            None => None,
+        };
+
+        // Courbe de croissance; les sauvegardes qui n'en ont pas stockaient l'XP du niveau en cours
+        let (croissance, experience) = match parts
+            .get(17)
+            .and_then(|courbe| CourbeCroissance::depuis_nom(courbe))
+        {
+            Some(croissance) => (croissance, experience),
+            None => {
+                let croissance = CourbeCroissance::default();
+                let debut = croissance.experience_pour_niveau(niveau);
+                (croissance, debut + experience.min(99))
+            }
+        };
+
+        // Données de l'espèce redemandées à la source pour les sauvegardes incomplètes
+        let espece = if parts.len() < 29 {
+            espece_id.and_then(|id| self.source().espece(id).ok())
+        } else {
+            None
+        };
+
+        let evolutions = match parts.get(18) {
+            Some(evolutions) => evolutions_depuis_texte(evolutions),
+            None => espece
+                .as_ref()
+                .map(|espece| espece.evolutions.clone())
+                .unwrap_or_default(),
+        };
+        let historique = parts
+            .get(19)
+            .map(|historique| {
+                historique
+                    .split(';')
+                    .filter(|evenement| !evenement.is_empty())
+                    .map(desechapper)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let groupes_oeuf = match parts.get(20) {
+            Some(groupes) => groupes_depuis_texte(groupes),
+            None => espece
+                .as_ref()
+                .map(|espece| espece.groupes_oeuf.clone())
+                .unwrap_or_default(),
+        };
+
+        let repartition_genres = match parts.get(21).and_then(|taux| taux.parse().ok()) {
+            Some(taux) => RepartitionGenres::depuis_taux(taux),
+            None => espece
+                .as_ref()
+                .map(|espece| espece.repartition_genres)
+                .unwrap_or_default(),
+        };
+
+        let chromatique = parts.get(22) == Some(&"true");
+        let origine = parts
+            .get(23)
+            .map(|origine| desechapper(origine))
+            .unwrap_or_else(|| ORIGINE_PAR_DEFAUT.to_string());
+
+        // Attaques (celles du niveau pour les sauvegardes qui n'en ont pas)
+        let apprentissage = match parts.get(25) {
+            Some(apprentissage) => apprentissage_depuis_texte(apprentissage),
+            None => espece
+                .as_ref()
+                .map(|espece| espece.apprentissage.clone())
+                .unwrap_or_default(),
+        };
+        let attaques = match parts.get(24) {
+            Some(attaques) => attaques
+                .split(',')
+                .filter(|attaque| !attaque.is_empty())
+                .map(str::to_string)
+                .collect(),
+            None => attaques_au_niveau(&apprentissage, niveau),
+        };
+
+        let attaques_oeuf = match parts.get(26) {
+            Some(attaques) => attaques
+                .split(',')
+                .filter(|attaque| !attaque.is_empty())
+                .map(str::to_string)
+                .collect(),
+            None => espece
+                .as_ref()
+                .map(|espece| espece.attaques_oeuf.clone())
+                .unwrap_or_default(),
+        };
+
+        // Talent (tiré parmi ceux de l'espèce pour les sauvegardes qui n'en ont pas)
+        let talents = match parts.get(28) {
+            Some(talents) => talents_depuis_texte(talents),
+            None => espece
+                .as_ref()
+                .map(|espece| espece.talents.clone())
+                .unwrap_or_default(),
+        };
+        let talent = match parts.get(27) {
+            Some(talent) => Talent::depuis_texte(talent),
+            None => tirer_talent(&talents),
+        };
+
+        // Évolution déclenchée mais pas encore confirmée
+        let evolution_en_attente = parts
+            .get(29)
+            .and_then(|evolution| Evolution::depuis_texte(evolution));
+
+        // Attaques qui attendent qu'une autre soit oubliée
+        let attaques_en_attente = parts
+            .get(30)
+            .map(|attaques| {
+                attaques
+                    .split(',')
+                    .filter(|attaque| !attaque.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Créer le Pokémon et l'ajouter à l'élevage
+        let mut pokemon = Pokemon {
+            id,
+            nom,
+            espece_id,
+            nom_espece,
+            niveau,
+            type_pokemon,
+            type_secondaire,
+            experience,
+            croissance,
+            genre,
+            repartition_genres,
+            mere_id,
+            pere_id,
+            generation,
+            malus_consanguinite,
+            ivs,
+            stats_de_base,
+            evs,
+            stats: Stats::default(),
+            nature,
+            objet,
+            evolutions,
+            evolution_en_attente,
+            historique,
+            groupes_oeuf,
+            chromatique,
+            origine,
+            attaques,
+            apprentissage,
+            attaques_en_attente,
+            attaques_oeuf,
+            talent,
+            talents,
+        };
+        pokemon.recalculer_stats();
+        Some(pokemon)
+    }
+}
+
+// Filiation d'un Pokémon relâché: "id|mère|père|génération"
+fn lire_filiation(ligne: &str) -> Option<Filiation> {
+    let parts: Vec<&str> = ligne.split('|').collect();
+    if parts.len() < 4 {
+        return None;
+    }
+    Some(Filiation {
+        id: parts[0].parse().ok()?,
+        mere_id: parts[1].parse().ok(),
+        pere_id: parts[2].parse().ok(),
+        generation: parts[3].parse().unwrap_or(0),
+    })
+}
+
+// Ligne de sauvegarde d'un Pokémon (colonnes séparées par des '|')
+fn ligne_pokemon(pokemon: &Pokemon) -> String {
+    format!(
+        "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
+        echapper(&pokemon.nom),
+        pokemon.niveau,
+        pokemon.libelle_types(),
+        pokemon.experience,
+        pokemon.genre,
+        pokemon
+            .espece_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        echapper(&pokemon.nom_espece),
+        pokemon.id,
+        pokemon.mere_id.map(|id| id.to_string()).unwrap_or_default(),
+        pokemon.pere_id.map(|id| id.to_string()).unwrap_or_default(),
+        pokemon.generation,
+        pokemon.malus_consanguinite,
+        pokemon.ivs.en_texte(),
+        pokemon.stats_de_base.en_texte(),
+        pokemon.evs.en_texte(),
+        pokemon.nature,
+        pokemon
+            .objet
+            .map(|objet| objet.to_string())
+            .unwrap_or_default(),
+        pokemon.croissance,
+        evolutions_en_texte(&pokemon.evolutions),
+        pokemon
+            .historique
+            .iter()
+            .map(|evenement| echapper(evenement))
+            .collect::<Vec<_>>()
+            .join(";"),
+        groupes_en_texte(&pokemon.groupes_oeuf),
+        pokemon.repartition_genres.taux(),
+        pokemon.chromatique,
+        echapper(&pokemon.origine),
+        pokemon.attaques.join(","),
+        apprentissage_en_texte(&pokemon.apprentissage),
+        pokemon.attaques_oeuf.join(","),
+        pokemon
+            .talent
+            .as_ref()
+            .map(Talent::en_texte)
+            .unwrap_or_default(),
+        talents_en_texte(&pokemon.talents),
+        pokemon
+            .evolution_en_attente
+            .as_ref()
+            .map(Evolution::en_texte)
+            .unwrap_or_default(),
+        pokemon.attaques_en_attente.join(",")
+    )
+}
+
+// Échapper un texte libre (surnom, origine, historique) pour qu'il ne contienne aucun séparateur:
+// '\' devient "\\", '|' devient "\b", ';' devient "\p" et un saut de ligne "\n"
+fn echapper(texte: &str) -> String {
+    let mut resultat = String::with_capacity(texte.len());
+    for caractere in texte.chars() {
+        match caractere {
+            '\\' => resultat.push_str("\\\\"),
+            '|' => resultat.push_str("\\b"),
+            ';' => resultat.push_str("\\p"),
+            '\n' => resultat.push_str("\\n"),
+            _ => resultat.push(caractere),
+        }
+    }
+    resultat
+}
+
+// Retrouver un texte échappé; les séquences inconnues sont gardées telles quelles
+fn desechapper(texte: &str) -> String {
+    let mut resultat = String::with_capacity(texte.len());
+    let mut caracteres = texte.chars();
+    while let Some(caractere) = caracteres.next() {
+        if caractere != '\\' {
+            resultat.push(caractere);
+            continue;
+        }
+        match caracteres.next() {
+            Some('\\') => resultat.push('\\'),
+            Some('b') => resultat.push('|'),
+            Some('p') => resultat.push(';'),
+            Some('n') => resultat.push('\n'),
+            Some(autre) => {
+                resultat.push('\\');
+                resultat.push(autre);
+            }
+            None => resultat.push('\\'),
+        }
+    }
+    resultat
+}
+
+// Convertir un nom de type sauvegardé
+fn convertir_type(nom: &str) -> TypePokemon {
+    match nom {
+        "Feu" => TypePokemon::Feu,
+        "Eau" => TypePokemon::Eau,
+        "Plante" => TypePokemon::Plante,
+        "Electrik" => TypePokemon::Electrik,
+        "Normal" => TypePokemon::Normal,
+        "Psy" => TypePokemon::Psy,
+        "Poison" => TypePokemon::Poison,
+        "Sol" => TypePokemon::Sol,
+        "Vol" => TypePokemon::Vol,
+        "Combat" => TypePokemon::Combat,
+        "Roche" => TypePokemon::Roche,
+        "Insecte" => TypePokemon::Insecte,
+        "Spectre" => TypePokemon::Spectre,
+        "Glace" => TypePokemon::Glace,
+        "Dragon" => TypePokemon::Dragon,
+        "Fée" => TypePokemon::Fee,
+        "Acier" => TypePokemon::Acier,
+        "Ténèbres" => TypePokemon::Tenebres,
+        _ => TypePokemon::Normal, // Par défaut
+    }
+}
@@ -0,0 +1,179 @@
+use rand::Rng;
+use std::fmt;
+
+use crate::nature::Nature;
+
+// Valeur maximale d'un IV
+pub const IV_MAX: u32 = 31;
+
+// Plafonds des EV: par statistique et au total
+pub const EV_MAX_PAR_STAT: u32 = 252;
+pub const EV_MAX_TOTAL: u32 = 510;
+
+// Les six statistiques d'un Pokémon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Statistique {
+    Pv,
+    Attaque,
+    Defense,
+    AttaqueSpe,
+    DefenseSpe,
+    Vitesse,
+}
+
+impl Statistique {
+    pub const TOUTES: [Statistique; 6] = [
+        Statistique::Pv,
+        Statistique::Attaque,
+        Statistique::Defense,
+        Statistique::AttaqueSpe,
+        Statistique::DefenseSpe,
+        Statistique::Vitesse,
+    ];
+
+    // Nom court utilisé dans les affichages compacts
+    pub fn abreviation(&self) -> &'static str {
+        match self {
+            Statistique::Pv => "PV",
+            Statistique::Attaque => "Atq",
+            Statistique::Defense => "Déf",
+            Statistique::AttaqueSpe => "Atq Spé",
+            Statistique::DefenseSpe => "Déf Spé",
+            Statistique::Vitesse => "Vit",
+        }
+    }
+}
+
+impl fmt::Display for Statistique {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Statistique::Pv => write!(f, "PV"),
+            Statistique::Attaque => write!(f, "Attaque"),
+            Statistique::Defense => write!(f, "Défense"),
+            Statistique::AttaqueSpe => write!(f, "Attaque Spé."),
+            Statistique::DefenseSpe => write!(f, "Défense Spé."),
+            Statistique::Vitesse => write!(f, "Vitesse"),
+        }
+    }
+}
+
+// Une valeur pour chacune des six statistiques (IV, statistiques de base...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub pv: u32,
+    pub attaque: u32,
+    pub defense: u32,
+    pub attaque_spe: u32,
+    pub defense_spe: u32,
+    pub vitesse: u32,
+}
+
+impl Stats {
+    // Même valeur pour les six statistiques
+    pub fn uniformes(valeur: u32) -> Self {
+        Stats {
+            pv: valeur,
+            attaque: valeur,
+            defense: valeur,
+            attaque_spe: valeur,
+            defense_spe: valeur,
+            vitesse: valeur,
+        }
+    }
+
+    // IV tirés au hasard entre 0 et 31
+    pub fn ivs_aleatoires() -> Self {
+        let mut rng = rand::thread_rng();
+        let mut ivs = Stats::default();
+        for stat in Statistique::TOUTES {
+            ivs.definir(stat, rng.gen_range(0..=IV_MAX));
+        }
+        ivs
+    }
+
+    pub fn valeur(&self, stat: Statistique) -> u32 {
+        match stat {
+            Statistique::Pv => self.pv,
+            Statistique::Attaque => self.attaque,
+            Statistique::Defense => self.defense,
+            Statistique::AttaqueSpe => self.attaque_spe,
+            Statistique::DefenseSpe => self.defense_spe,
+            Statistique::Vitesse => self.vitesse,
+        }
+    }
+
+    pub fn definir(&mut self, stat: Statistique, valeur: u32) {
+        match stat {
+            Statistique::Pv => self.pv = valeur,
+            Statistique::Attaque => self.attaque = valeur,
+            Statistique::Defense => self.defense = valeur,
+            Statistique::AttaqueSpe => self.attaque_spe = valeur,
+            Statistique::DefenseSpe => self.defense_spe = valeur,
+            Statistique::Vitesse => self.vitesse = valeur,
+        }
+    }
+
+    // Somme des six valeurs
+    pub fn total(&self) -> u32 {
+        Statistique::TOUTES
+            .iter()
+            .map(|stat| self.valeur(*stat))
+            .sum()
+    }
+
+    // Format de sauvegarde: "pv,atq,def,atqspe,defspe,vit"
+    pub fn depuis_texte(texte: &str) -> Option<Self> {
+        let valeurs: Vec<u32> = texte
+            .split(',')
+            .map(|valeur| valeur.parse().ok())
+            .collect::<Option<_>>()?;
+        if valeurs.len() != 6 {
+            return None;
+        }
+        let mut stats = Stats::default();
+        for (stat, valeur) in Statistique::TOUTES.into_iter().zip(valeurs) {
+            stats.definir(stat, valeur);
+        }
+        Some(stats)
+    }
+
+    pub fn en_texte(&self) -> String {
+        Statistique::TOUTES
+            .iter()
+            .map(|stat| self.valeur(*stat).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+// Statistiques réelles selon la formule des jeux principaux (depuis la 3e génération)
+pub fn calculer_stats(
+    stats_de_base: &Stats,
+    ivs: &Stats,
+    evs: &Stats,
+    niveau: u32,
+    nature: Nature,
+) -> Stats {
+    let mut stats = Stats::default();
+    for stat in Statistique::TOUTES {
+        let brut = (2 * stats_de_base.valeur(stat) + ivs.valeur(stat) + evs.valeur(stat) / 4)
+            * niveau
+            / 100;
+        let valeur = match stat {
+            Statistique::Pv => brut + niveau + 10,
+            _ => nature.appliquer(stat, brut + 5),
+        };
+        stats.definir(stat, valeur);
+    }
+    stats
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let valeurs: Vec<String> = Statistique::TOUTES
+            .iter()
+            .map(|stat| format!("{} {}", stat.abreviation(), self.valeur(*stat)))
+            .collect();
+        write!(f, "{}", valeurs.join(" / "))
+    }
+}
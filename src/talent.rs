@@ -0,0 +1,111 @@
+use std::fmt;
+
+use rand::seq::SliceRandom;
+
+use crate::attaques::nom_attaque;
+
+// Pourcentage de chances qu'une mère au talent caché le transmette à son petit
+pub const CHANCES_TALENT_CACHE: u32 = 60;
+
+// Talent d'une espèce; le talent caché est plus rare que les autres
+#[derive(Debug, Clone, PartialEq)]
+pub struct Talent {
+    pub nom: String,
+    pub cache: bool,
+}
+
+impl Talent {
+    // Talent à partir de son nom PokéAPI ("solar-power" -> "Solar Power")
+    pub fn depuis_api(nom_api: &str, cache: bool) -> Self {
+        Talent {
+            nom: nom_attaque(nom_api),
+            cache,
+        }
+    }
+
+    // Format de sauvegarde: "Overgrow" ou "Chlorophyll:cache"
+    pub fn en_texte(&self) -> String {
+        if self.cache {
+            format!("{}:cache", self.nom)
+        } else {
+            self.nom.clone()
+        }
+    }
+
+    pub fn depuis_texte(texte: &str) -> Option<Self> {
+        if texte.is_empty() {
+            return None;
+        }
+        Some(match texte.strip_suffix(":cache") {
+            Some(nom) => Talent {
+                nom: nom.to_string(),
+                cache: true,
+            },
+            None => Talent {
+                nom: texte.to_string(),
+                cache: false,
+            },
+        })
+    }
+}
+
+impl fmt::Display for Talent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.cache {
+            write!(f, "{} (caché)", self.nom)
+        } else {
+            write!(f, "{}", self.nom)
+        }
+    }
+}
+
+// Tirer l'un des talents ordinaires d'une espèce (le talent caché s'il n'y en a pas d'autre)
+pub fn tirer_talent(talents: &[Talent]) -> Option<Talent> {
+    let ordinaires: Vec<&Talent> = talents.iter().filter(|talent| !talent.cache).collect();
+    let mut rng = rand::thread_rng();
+    match ordinaires.choose(&mut rng) {
+        Some(talent) => Some((*talent).clone()),
+        None => talents.choose(&mut rng).cloned(),
+    }
+}
+
+// Talent caché d'une espèce, s'il est connu
+pub fn talent_cache(talents: &[Talent]) -> Option<Talent> {
+    talents.iter().find(|talent| talent.cache).cloned()
+}
+
+// Talent correspondant après une évolution: même emplacement parmi les talents ordinaires,
+// ou talent caché de la nouvelle espèce
+pub fn talent_apres_evolution(
+    talent: Option<&Talent>,
+    anciens: &[Talent],
+    nouveaux: &[Talent],
+) -> Option<Talent> {
+    let Some(talent) = talent else {
+        return tirer_talent(nouveaux);
+    };
+    if talent.cache {
+        return talent_cache(nouveaux).or_else(|| tirer_talent(nouveaux));
+    }
+    let emplacement = anciens
+        .iter()
+        .filter(|ancien| !ancien.cache)
+        .position(|ancien| ancien == talent);
+    emplacement
+        .and_then(|index| nouveaux.iter().filter(|nouveau| !nouveau.cache).nth(index))
+        .cloned()
+        .or_else(|| tirer_talent(nouveaux))
+}
+
+// Talents au format "Overgrow,Chlorophyll:cache"
+pub fn talents_en_texte(talents: &[Talent]) -> String {
+    talents
+        .iter()
+        .map(Talent::en_texte)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn talents_depuis_texte(texte: &str) -> Vec<Talent> {
+    texte.split(',').filter_map(Talent::depuis_texte).collect()
+}
@@ -0,0 +1,250 @@
+mod commun;
+
+use std::time::Duration;
+
+use commun::{ServeurStub, fixture, repertoire_temporaire, routes_bulbasaur, routes_pikachu};
+use elevage_pokemon_cc1::api::PokeApi;
+use elevage_pokemon_cc1::config::ConfigApi;
+use elevage_pokemon_cc1::croissance::CourbeCroissance;
+use elevage_pokemon_cc1::especes::{Espece, SourceMemoire};
+use elevage_pokemon_cc1::evolution::{Declencheur, Evolution};
+use elevage_pokemon_cc1::nature::Nature;
+use elevage_pokemon_cc1::stats::Stats;
+use elevage_pokemon_cc1::{Elevage, Pokemon, SpeciesSource, TypePokemon};
+
+// Pikachu, dont la première réponse pour pokemon/25 est une erreur serveur
+fn routes_pikachu_erreur_500() -> commun::Routes {
+    let mut routes = routes_pikachu();
+    routes[0].1.insert(0, (500, "{}".to_string()));
+    routes
+}
+
+#[test]
+fn from_api_utilise_l_url_configuree() {
+    let serveur = ServeurStub::demarrer(routes_pikachu());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let pikachu = Pokemon::from_api(&source, 25).unwrap();
+
+    assert_eq!(pikachu.nom, "Pikachu");
+    assert_eq!(pikachu.espece_id, Some(25));
+    assert_eq!(pikachu.nom_espece, "Pikachu");
+    assert_eq!(pikachu.type_pokemon, TypePokemon::Electrik);
+    assert_eq!(pikachu.niveau, 1);
+    assert_eq!(serveur.nombre_requetes(), 2);
+}
+
+#[test]
+fn espece_conserve_tous_les_types() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let bulbasaur = source.espece(1).unwrap();
+
+    assert_eq!(bulbasaur.nom, "Bulbasaur");
+    assert_eq!(
+        bulbasaur.types,
+        vec![TypePokemon::Plante, TypePokemon::Poison]
+    );
+}
+
+#[test]
+fn from_api_garde_le_type_secondaire() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let bulbasaur = Pokemon::from_api(&source, 1).unwrap();
+
+    assert_eq!(bulbasaur.type_pokemon, TypePokemon::Plante);
+    assert_eq!(bulbasaur.type_secondaire, Some(TypePokemon::Poison));
+    assert_eq!(bulbasaur.libelle_types(), "Plante/Poison");
+}
+
+#[test]
+fn stats_calculees_depuis_les_stats_de_base() {
+    let serveur = ServeurStub::demarrer(routes_pikachu());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let mut pikachu = Pokemon::from_api(&source, 25).unwrap();
+    pikachu.ivs = Stats::uniformes(31);
+    pikachu.nature = Nature::Hardi;
+    pikachu.fixer_niveau(50);
+
+    assert_eq!(pikachu.stats_de_base.pv, 35);
+    assert_eq!(pikachu.stats_de_base.vitesse, 90);
+    assert_eq!(pikachu.stats.pv, 110);
+    assert_eq!(pikachu.stats.vitesse, 110);
+
+    // Timide: +10% en Vitesse, -10% en Attaque
+    pikachu.nature = Nature::Timide;
+    pikachu.recalculer_stats();
+    assert_eq!(pikachu.stats.vitesse, 121);
+    assert_eq!(pikachu.stats.attaque, 67);
+}
+
+#[test]
+fn courbe_de_croissance_depuis_l_espece() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let mut bulbasaur = Pokemon::from_api(&source, 1).unwrap();
+    assert_eq!(bulbasaur.croissance, CourbeCroissance::MoyenneLente);
+
+    // Moyenne-Lente: 560 XP pour le niveau 10, 1 059 860 pour le niveau 100
+    bulbasaur.gagner_xp(560);
+    assert_eq!(bulbasaur.niveau, 10);
+    assert_eq!(bulbasaur.progression(), Some((0, 182)));
+
+    bulbasaur.gagner_xp(2_000_000);
+    assert_eq!(bulbasaur.niveau, 100);
+    assert_eq!(bulbasaur.experience, 1_059_860);
+    assert_eq!(bulbasaur.progression(), None);
+}
+
+#[test]
+fn evolution_au_niveau_de_la_chaine() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let mut bulbasaur = Pokemon::from_api(&source, 1).unwrap();
+    assert_eq!(
+        bulbasaur.evolutions,
+        vec![Evolution {
+            espece_id: 2,
+            nom_espece: "Ivysaur".to_string(),
+            declencheur: Declencheur::Niveau(16),
+        }]
+    );
+
+    bulbasaur.fixer_niveau(15);
+    assert_eq!(bulbasaur.evolution_en_attente, None);
+    bulbasaur.gagner_xp(1000);
+    assert_eq!(bulbasaur.niveau, 16);
+
+    // L'évolution attend la confirmation de l'éleveur
+    let ivysaur = Espece {
+        id: 2,
+        nom: "Ivysaur".to_string(),
+        types: vec![TypePokemon::Plante, TypePokemon::Poison],
+        ..Espece::default()
+    };
+    let mut elevage = Elevage::avec_source(Box::new(SourceMemoire::new(vec![ivysaur])));
+    let id = elevage.ajouter_pokemon(bulbasaur);
+    assert_eq!(elevage.evolutions_en_attente().len(), 1);
+
+    elevage.faire_evoluer(id).unwrap();
+
+    let ivysaur = elevage.pokemon_par_id(id).unwrap();
+    assert_eq!(ivysaur.espece_id, Some(2));
+    assert_eq!(ivysaur.nom, "Ivysaur");
+    assert_eq!(ivysaur.evolution_en_attente, None);
+    assert_eq!(ivysaur.historique.len(), 1);
+}
+
+#[test]
+fn attaques_apprises_par_niveau() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    // Seules les attaques apprises par niveau dans Rouge/Bleu sont retenues
+    let espece = source.espece(1).unwrap();
+    let niveaux: Vec<(u32, &str)> = espece
+        .apprentissage
+        .iter()
+        .map(|attaque| (attaque.niveau, attaque.nom.as_str()))
+        .collect();
+    assert_eq!(
+        niveaux,
+        vec![
+            (1, "Tackle"),
+            (1, "Growl"),
+            (7, "Leech Seed"),
+            (13, "Vine Whip"),
+            (20, "Poison Powder")
+        ]
+    );
+
+    let mut bulbasaur = Pokemon::depuis_espece(&espece);
+    assert_eq!(bulbasaur.attaques, vec!["Tackle", "Growl"]);
+
+    bulbasaur.fixer_niveau(12);
+    bulbasaur.gagner_xp(100_000);
+    assert_eq!(
+        bulbasaur.attaques,
+        vec!["Tackle", "Growl", "Leech Seed", "Vine Whip"]
+    );
+    assert_eq!(bulbasaur.attaques_en_attente, vec!["Poison Powder"]);
+}
+
+#[test]
+fn liste_especes_depuis_le_miroir() {
+    let serveur = ServeurStub::demarrer(vec![(
+        "pokemon?limit=151",
+        vec![(200, fixture("pokemon_liste.json"))],
+    )]);
+    let source = PokeApi::avec_config(serveur.config());
+
+    let noms = source.liste_especes().unwrap();
+
+    assert_eq!(noms, vec!["Bulbasaur", "Ivysaur", "Venusaur"]);
+}
+
+#[test]
+fn user_agent_configure_est_envoye() {
+    let serveur = ServeurStub::demarrer(routes_pikachu());
+    let config = ConfigApi {
+        user_agent: "miroir-test/1.0".to_string(),
+        ..serveur.config()
+    };
+
+    Pokemon::from_api(&PokeApi::avec_config(config), 25).unwrap();
+
+    let requetes = serveur.requetes.lock().unwrap();
+    assert!(
+        requetes[0]
+            .to_lowercase()
+            .contains("user-agent: miroir-test/1.0")
+    );
+}
+
+#[test]
+fn erreur_serveur_est_retentee() {
+    let serveur = ServeurStub::demarrer(routes_pikachu_erreur_500());
+    let config = ConfigApi {
+        tentatives: 2,
+        ..serveur.config()
+    };
+
+    let pikachu = Pokemon::from_api(&PokeApi::avec_config(config), 25).unwrap();
+
+    assert_eq!(pikachu.nom, "Pikachu");
+    assert_eq!(serveur.nombre_requetes(), 3);
+}
+
+#[test]
+fn sans_tentative_l_erreur_serveur_remonte() {
+    let serveur = ServeurStub::demarrer(routes_pikachu_erreur_500());
+    let source = PokeApi::avec_config(serveur.config());
+
+    assert!(Pokemon::from_api(&source, 25).is_err());
+    assert_eq!(serveur.nombre_requetes(), 1);
+}
+
+#[test]
+fn config_depuis_fichier() {
+    let repertoire = repertoire_temporaire("config");
+    std::fs::create_dir_all(&repertoire).unwrap();
+    let fichier = repertoire.join("elevage.conf");
+    std::fs::write(
+        &fichier,
+        "# Miroir local\nurl_base = http://localhost:8000/api/v2/\ndelai = 3\ntentatives = 5\nuser_agent = ferme\n",
+    )
+    .unwrap();
+
+    let config = ConfigApi::depuis_fichier(&fichier).unwrap();
+
+    assert_eq!(config.url_base, "http://localhost:8000/api/v2");
+    assert_eq!(config.delai, Duration::from_secs(3));
+    assert_eq!(config.tentatives, 5);
+    assert_eq!(config.user_agent, "ferme");
+}
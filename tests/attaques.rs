@@ -0,0 +1,89 @@
+mod commun;
+
+use commun::repertoire_temporaire;
+use elevage_pokemon_cc1::embarque::DonneesEmbarquees;
+use elevage_pokemon_cc1::{Elevage, Genre, Pokemon, TypePokemon};
+
+// Bulbizarre de niveau 19 qui connaît quatre attaques et monte au niveau 20 (Poison Powder)
+fn bulbizarre_en_attente() -> (Elevage, u32) {
+    let mut elevage = Elevage::new();
+    let mut bulbizarre = Pokemon::from_api(&DonneesEmbarquees::new(), 1).unwrap();
+    bulbizarre.fixer_niveau(19);
+    let id = elevage.ajouter_pokemon(bulbizarre);
+
+    let pokemon = elevage.pokemon_par_id_mut(id).unwrap();
+    assert_eq!(
+        pokemon.attaques,
+        vec!["Tackle", "Growl", "Leech Seed", "Vine Whip"]
+    );
+    let manquant = pokemon.croissance.experience_pour_niveau(20) - pokemon.experience;
+    pokemon.gagner_xp(manquant);
+    (elevage, id)
+}
+
+#[test]
+fn attaque_remplacee_avec_quatre_attaques() {
+    let (mut elevage, id) = bulbizarre_en_attente();
+    assert_eq!(
+        elevage.attaques_en_attente(),
+        vec![(id, "Poison Powder".to_string())]
+    );
+
+    // La deuxième attaque est oubliée au profit de la nouvelle
+    elevage.apprendre_attaque(id, "Poison Powder", Some(1));
+    let bulbizarre = elevage.pokemon_par_id(id).unwrap();
+    assert_eq!(
+        bulbizarre.attaques,
+        vec!["Tackle", "Poison Powder", "Leech Seed", "Vine Whip"]
+    );
+    assert!(elevage.attaques_en_attente().is_empty());
+}
+
+#[test]
+fn renoncer_a_une_attaque() {
+    let (mut elevage, id) = bulbizarre_en_attente();
+    elevage.apprendre_attaque(id, "Poison Powder", None);
+    let bulbizarre = elevage.pokemon_par_id(id).unwrap();
+    assert_eq!(
+        bulbizarre.attaques,
+        vec!["Tackle", "Growl", "Leech Seed", "Vine Whip"]
+    );
+    assert!(elevage.attaques_en_attente().is_empty());
+}
+
+#[test]
+fn attaques_en_attente_sauvegardees() {
+    let (elevage, id) = bulbizarre_en_attente();
+    let repertoire = repertoire_temporaire("attaques");
+    std::fs::create_dir_all(&repertoire).unwrap();
+    let fichier = repertoire.join("elevage.txt");
+    elevage.sauvegarder(fichier.to_str().unwrap()).unwrap();
+
+    let mut charge = Elevage::new();
+    charge.charger(fichier.to_str().unwrap()).unwrap();
+    std::fs::remove_dir_all(&repertoire).unwrap();
+    assert_eq!(
+        charge.attaques_en_attente(),
+        vec![(id, "Poison Powder".to_string())]
+    );
+    charge.apprendre_attaque(id, "Poison Powder", Some(0));
+    assert_eq!(
+        charge.pokemon_par_id(id).unwrap().attaques[0],
+        "Poison Powder"
+    );
+}
+
+#[test]
+fn donnees_d_attaques_manquantes_signalees() {
+    let source = DonneesEmbarquees::new();
+    // Les données embarquées n'ont pas les attaques de Miaouss
+    let miaouss = Pokemon::from_api(&source, 52).unwrap();
+    assert!(miaouss.attaques.is_empty());
+    assert!(miaouss.donnees_manquantes().contains(&"attaques"));
+
+    let bulbizarre = Pokemon::from_api(&source, 1).unwrap();
+    assert!(!bulbizarre.donnees_manquantes().contains(&"attaques"));
+    // Un Pokémon créé sans espèce n'a pas de données à attendre
+    let sans_espece = Pokemon::new("Inconnu".to_string(), TypePokemon::Normal, Genre::Male);
+    assert!(sans_espece.donnees_manquantes().is_empty());
+}
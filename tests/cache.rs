@@ -0,0 +1,164 @@
+mod commun;
+
+use std::fs;
+
+use commun::{ServeurStub, fixture, repertoire_temporaire, routes_pikachu};
+use elevage_pokemon_cc1::SpeciesSource;
+use elevage_pokemon_cc1::api::PokeApi;
+use elevage_pokemon_cc1::cache::CacheHttp;
+use reqwest::blocking::Client;
+
+#[test]
+fn cache_evite_une_seconde_requete() {
+    let serveur = ServeurStub::demarrer(routes_pikachu());
+    let repertoire = repertoire_temporaire("cache");
+    let source = PokeApi::avec_config(serveur.config()).avec_cache(CacheHttp::new(&repertoire));
+
+    source.espece(25).unwrap();
+    let pikachu = source.espece(25).unwrap();
+
+    assert_eq!(pikachu.nom, "Pikachu");
+    assert_eq!(serveur.nombre_requetes(), 2);
+    assert_eq!(CacheHttp::new(&repertoire).vider().unwrap(), 2);
+}
+
+#[test]
+fn vider_ne_supprime_que_les_fichiers_du_cache() {
+    let serveur = ServeurStub::demarrer(routes_pikachu());
+    let repertoire = repertoire_temporaire("cache-vider");
+    let source = PokeApi::avec_config(serveur.config()).avec_cache(CacheHttp::new(&repertoire));
+    source.espece(25).unwrap();
+
+    // Fichiers de l'utilisateur rangés dans le même répertoire
+    fs::write(repertoire.join("important.txt"), "à garder").unwrap();
+    fs::write(repertoire.join("notes.json"), "{}").unwrap();
+    fs::write(repertoire.join("notes.meta"), "url=ailleurs").unwrap();
+    fs::create_dir_all(repertoire.join("sous-repertoire")).unwrap();
+
+    assert_eq!(CacheHttp::new(&repertoire).vider().unwrap(), 2);
+
+    let mut restants: Vec<String> = fs::read_dir(&repertoire)
+        .unwrap()
+        .map(|entree| entree.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    restants.sort();
+    assert_eq!(
+        restants,
+        vec![
+            "important.txt",
+            "notes.json",
+            "notes.meta",
+            "sous-repertoire"
+        ]
+    );
+    assert_eq!(CacheHttp::new(&repertoire).vider().unwrap(), 0);
+}
+
+// Liste des espèces, servie avec les en-têtes donnés
+fn serveur_liste(reponses: Vec<(u16, String)>, entetes: &[&str]) -> ServeurStub {
+    ServeurStub::demarrer_avec_entetes(vec![("pokemon?limit=151", reponses)], entetes)
+}
+
+#[test]
+fn reponse_expiree_revalidee_avec_l_etag() {
+    let serveur = serveur_liste(
+        vec![(200, fixture("pokemon_liste.json")), (304, String::new())],
+        &["ETag: \"v1\"", "Cache-Control: max-age=0"],
+    );
+    let repertoire = repertoire_temporaire("cache-etag");
+    let source = PokeApi::avec_config(serveur.config()).avec_cache(CacheHttp::new(&repertoire));
+
+    source.liste_especes().unwrap();
+    // Réponse expirée: le serveur répond 304 et le corps en cache est réutilisé
+    let noms = source.liste_especes().unwrap();
+
+    assert_eq!(noms, vec!["Bulbasaur", "Ivysaur", "Venusaur"]);
+    let requetes = serveur.requetes.lock().unwrap();
+    assert_eq!(requetes.len(), 2);
+    assert!(!requetes[0].to_lowercase().contains("if-none-match"));
+    assert!(requetes[1].to_lowercase().contains("if-none-match: \"v1\""));
+}
+
+#[test]
+fn reponse_valide_pendant_max_age() {
+    let serveur = serveur_liste(
+        vec![(200, fixture("pokemon_liste.json"))],
+        &["Cache-Control: public, max-age=3600"],
+    );
+    let repertoire = repertoire_temporaire("cache-max-age");
+    let source = PokeApi::avec_config(serveur.config()).avec_cache(CacheHttp::new(&repertoire));
+
+    source.liste_especes().unwrap();
+    source.liste_especes().unwrap();
+
+    assert_eq!(serveur.nombre_requetes(), 1);
+}
+
+#[test]
+fn reponse_perimee_utilisee_hors_ligne() {
+    let serveur = serveur_liste(
+        vec![(200, fixture("pokemon_liste.json"))],
+        &["Cache-Control: max-age=0"],
+    );
+    let repertoire = repertoire_temporaire("cache-hors-ligne");
+    let source = PokeApi::avec_config(serveur.config()).avec_cache(CacheHttp::new(&repertoire));
+    source.liste_especes().unwrap();
+
+    // Serveur injoignable: la réponse expirée vaut mieux qu'une erreur
+    serveur.arreter();
+    let noms = source.liste_especes().unwrap();
+    assert_eq!(noms, vec!["Bulbasaur", "Ivysaur", "Venusaur"]);
+
+    // Sans réponse en cache, l'erreur remonte
+    let sans_cache = PokeApi::avec_config(serveur.config())
+        .avec_cache(CacheHttp::new(repertoire_temporaire("cache-vide")));
+    assert!(sans_cache.liste_especes().is_err());
+}
+
+#[test]
+fn reponse_perimee_utilisee_si_le_serveur_reste_en_erreur() {
+    let serveur = serveur_liste(
+        vec![
+            (200, fixture("pokemon_liste.json")),
+            (500, "{}".to_string()),
+        ],
+        &["Cache-Control: max-age=0"],
+    );
+    let repertoire = repertoire_temporaire("cache-erreur-serveur");
+    let source = PokeApi::avec_config(serveur.config()).avec_cache(CacheHttp::new(&repertoire));
+    source.liste_especes().unwrap();
+
+    // Le serveur répond 500: la réponse expirée est réutilisée
+    let noms = source.liste_especes().unwrap();
+    assert_eq!(noms, vec!["Bulbasaur", "Ivysaur", "Venusaur"]);
+    assert_eq!(serveur.nombre_requetes(), 2);
+
+    // Sans réponse en cache, l'erreur remonte
+    let sans_cache = PokeApi::avec_config(serveur.config())
+        .avec_cache(CacheHttp::new(repertoire_temporaire("cache-erreur-vide")));
+    assert!(sans_cache.liste_especes().is_err());
+}
+
+#[test]
+fn urls_de_meme_cle_non_confondues() {
+    // "pokemon?limit=151" et "pokemon_limit=151" donnent les mêmes noms de fichiers
+    let serveur = ServeurStub::demarrer_avec_entetes(
+        vec![
+            ("pokemon?limit=151", vec![(200, "\"liste\"".to_string())]),
+            ("pokemon_limit=151", vec![(200, "\"autre\"".to_string())]),
+        ],
+        &["Cache-Control: max-age=3600"],
+    );
+    let cache = CacheHttp::new(repertoire_temporaire("cache-collision"));
+    let client = Client::new();
+    let liste = format!("{}/pokemon?limit=151", serveur.url);
+    let autre = format!("{}/pokemon_limit=151", serveur.url);
+
+    assert_eq!(cache.get(&client, &liste, 0).unwrap(), "\"liste\"");
+    assert_eq!(cache.get(&client, &autre, 0).unwrap(), "\"autre\"");
+    assert_eq!(cache.get(&client, &liste, 0).unwrap(), "\"liste\"");
+    assert_eq!(serveur.nombre_requetes(), 3);
+    // L'entrée valide est servie sans nouvelle requête
+    assert_eq!(cache.get(&client, &liste, 0).unwrap(), "\"liste\"");
+    assert_eq!(serveur.nombre_requetes(), 3);
+}
@@ -0,0 +1,175 @@
+// Outils partagés par les tests d'intégration (chaque fichier n'en utilise qu'une partie)
+#![allow(dead_code)]
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use elevage_pokemon_cc1::config::ConfigApi;
+use elevage_pokemon_cc1::especes::Espece;
+use elevage_pokemon_cc1::groupe_oeuf::GroupeOeuf;
+use elevage_pokemon_cc1::{Genre, Pokemon, TypePokemon};
+
+// Routes du serveur: chaque chemin renvoie ses réponses (statut, corps) dans l'ordre
+pub type Routes = Vec<(&'static str, Vec<(u16, String)>)>;
+
+// Serveur HTTP minimal rejouant des réponses enregistrées
+pub struct ServeurStub {
+    pub url: String,
+    pub requetes: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServeurStub {
+    // Chaque chemin renvoie ses réponses dans l'ordre, la dernière étant répétée
+    pub fn demarrer(routes: Routes) -> Self {
+        Self::demarrer_avec_entetes(routes, &[])
+    }
+
+    // Serveur ajoutant des en-têtes (ETag, Cache-Control...) à toutes ses réponses
+    pub fn demarrer_avec_entetes(routes: Routes, entetes: &[&str]) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/api/v2", listener.local_addr().unwrap());
+        let requetes = Arc::new(Mutex::new(Vec::new()));
+        let mut routes: HashMap<String, VecDeque<(u16, String)>> = routes
+            .into_iter()
+            .map(|(chemin, reponses)| (format!("/api/v2/{}", chemin), reponses.into()))
+            .collect();
+        let entetes: String = entetes
+            .iter()
+            .map(|entete| format!("{}\r\n", entete))
+            .collect();
+
+        let journal = Arc::clone(&requetes);
+        thread::spawn(move || {
+            for flux in listener.incoming() {
+                let mut flux = flux.unwrap();
+                let mut lecteur = BufReader::new(flux.try_clone().unwrap());
+                let mut requete = String::new();
+                loop {
+                    let mut line = String::new();
+                    if lecteur.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    requete.push_str(&line);
+                }
+                // Requête vide: demande d'arrêt du serveur
+                if requete.is_empty() {
+                    break;
+                }
+                let chemin = requete.split_whitespace().nth(1).unwrap_or("").to_string();
+                journal.lock().unwrap().push(requete);
+
+                let (statut, corps) = match routes.get_mut(&chemin) {
+                    Some(reponses) if reponses.len() > 1 => reponses.pop_front().unwrap(),
+                    Some(reponses) => reponses[0].clone(),
+                    None => (404, "{}".to_string()),
+                };
+                let reponse = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Type: application/json\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    statut,
+                    entetes,
+                    corps.len(),
+                    corps
+                );
+                flux.write_all(reponse.as_bytes()).unwrap();
+            }
+        });
+
+        ServeurStub { url, requetes }
+    }
+
+    pub fn nombre_requetes(&self) -> usize {
+        self.requetes.lock().unwrap().len()
+    }
+
+    // Arrêter le serveur: les requêtes suivantes échouent à la connexion
+    pub fn arreter(&self) {
+        let adresse = self
+            .url
+            .trim_start_matches("http://")
+            .trim_end_matches("/api/v2");
+        drop(std::net::TcpStream::connect(adresse).unwrap());
+        // Laisser au serveur le temps de fermer son socket
+        thread::sleep(Duration::from_millis(100));
+    }
+
+    pub fn config(&self) -> ConfigApi {
+        ConfigApi {
+            url_base: self.url.clone(),
+            delai: Duration::from_secs(5),
+            tentatives: 0,
+            ..ConfigApi::default()
+        }
+    }
+}
+
+pub fn fixture(nom: &str) -> String {
+    let chemin = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(nom);
+    std::fs::read_to_string(chemin).unwrap()
+}
+
+// Bulbizarre: Pokémon, espèce et chaîne d'évolution
+pub fn routes_bulbasaur() -> Routes {
+    vec![
+        ("pokemon/1", vec![(200, fixture("pokemon_1.json"))]),
+        (
+            "pokemon-species/1",
+            vec![(200, fixture("pokemon_species_1.json"))],
+        ),
+        (
+            "evolution-chain/1",
+            vec![(200, fixture("evolution_chain_1.json"))],
+        ),
+    ]
+}
+
+// Pikachu: Pokémon et espèce (sans chaîne d'évolution)
+pub fn routes_pikachu() -> Routes {
+    vec![
+        ("pokemon/25", vec![(200, fixture("pokemon_25.json"))]),
+        (
+            "pokemon-species/25",
+            vec![(200, fixture("pokemon_species_25.json"))],
+        ),
+    ]
+}
+
+// Magicarpe, espèce des groupes Aquatique 2 et Draconique
+pub fn magicarpe() -> Espece {
+    Espece {
+        id: 129,
+        nom: "Magikarp".to_string(),
+        types: vec![TypePokemon::Eau],
+        groupes_oeuf: vec![GroupeOeuf::Eau2, GroupeOeuf::Dragon],
+        ..Espece::default()
+    }
+}
+
+// Parent prêt à se reproduire (niveau 10)
+pub fn parent(espece: &Espece, genre: Genre) -> Pokemon {
+    let mut pokemon = Pokemon::depuis_espece(espece);
+    pokemon.genre = genre;
+    pokemon.fixer_niveau(10);
+    pokemon
+}
+
+// Magicarpe de première génération, petit de deux Pokémon de l'élevage
+pub fn petit(mere: u32, pere: u32, genre: Genre) -> Pokemon {
+    let mut pokemon = parent(&magicarpe(), genre);
+    pokemon.mere_id = Some(mere);
+    pokemon.pere_id = Some(pere);
+    pokemon.generation = 1;
+    pokemon
+}
+
+pub fn repertoire_temporaire(nom: &str) -> PathBuf {
+    let repertoire = std::env::temp_dir().join(format!("elevage-{}-{}", nom, std::process::id()));
+    let _ = std::fs::remove_dir_all(&repertoire);
+    repertoire
+}
@@ -0,0 +1,100 @@
+mod commun;
+
+use commun::{magicarpe, parent, petit, repertoire_temporaire};
+use elevage_pokemon_cc1::regles::PolitiqueConsanguinite;
+use elevage_pokemon_cc1::stats::Statistique;
+use elevage_pokemon_cc1::{Elevage, Genre};
+
+// Une mère (1), un père (2) et leurs deux petits (3 et 4)
+fn famille() -> Elevage {
+    let mut elevage = Elevage::new();
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Femelle));
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    elevage.ajouter_pokemon(petit(1, 2, Genre::Femelle));
+    elevage.ajouter_pokemon(petit(1, 2, Genre::Male));
+    elevage
+}
+
+#[test]
+fn coefficients_de_parente() {
+    let mut elevage = famille();
+    // Un second père (5) et un demi-frère (6)
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    elevage.ajouter_pokemon(petit(1, 5, Genre::Male));
+
+    assert_eq!(elevage.coefficient_consanguinite(1, 2), 0.0);
+    assert_eq!(elevage.coefficient_consanguinite(1, 4), 0.25);
+    assert_eq!(elevage.coefficient_consanguinite(2, 3), 0.25);
+    assert_eq!(elevage.coefficient_consanguinite(3, 4), 0.25);
+    assert_eq!(elevage.coefficient_consanguinite(3, 6), 0.125);
+    assert_eq!(elevage.coefficient_consanguinite(2, 6), 0.0);
+}
+
+#[test]
+fn politique_interdire() {
+    let mut elevage = famille();
+    elevage.regles.politique_consanguinite = PolitiqueConsanguinite::Interdire;
+    assert!(!elevage.tenter_reproduction(3, 4));
+    assert!(!elevage.tenter_reproduction(1, 4));
+    assert!(elevage.oeufs.is_empty());
+
+    // Sous le seuil, la reproduction reste permise
+    elevage.regles.seuil_consanguinite = 0.5;
+    assert!(elevage.tenter_reproduction(3, 4));
+    assert_eq!(elevage.oeufs.len(), 1);
+}
+
+#[test]
+fn politique_penaliser() {
+    let mut elevage = famille();
+    elevage.regles.politique_consanguinite = PolitiqueConsanguinite::Penaliser;
+    assert!(elevage.tenter_reproduction(3, 4));
+    let mut bebe = elevage.oeufs[0].pokemon.clone();
+    assert_eq!(bebe.malus_consanguinite, 0.25);
+
+    // Les statistiques du petit sont réduites de 25 %, et le restent en montant de niveau
+    let penalise = bebe.stats;
+    bebe.malus_consanguinite = 0.0;
+    bebe.recalculer_stats();
+    for stat in Statistique::TOUTES {
+        let attendu = (f64::from(bebe.stats.valeur(stat)) * 0.75).round() as u32;
+        assert_eq!(penalise.valeur(stat), attendu.max(1), "{}", stat);
+    }
+
+    bebe.malus_consanguinite = 0.25;
+    bebe.fixer_niveau(50);
+    let penalise = bebe.stats;
+    bebe.malus_consanguinite = 0.0;
+    bebe.recalculer_stats();
+    assert!(penalise.total() < bebe.stats.total());
+
+    // Un couple sans lien de parenté n'est pas pénalisé
+    assert!(elevage.tenter_reproduction(1, 2));
+    assert_eq!(elevage.oeufs[1].pokemon.malus_consanguinite, 0.0);
+}
+
+#[test]
+fn parente_conservee_apres_le_depart_des_parents() {
+    let mut elevage = famille();
+    assert_eq!(elevage.coefficient_consanguinite(3, 4), 0.25);
+
+    elevage.retirer_pokemon(1);
+    elevage.retirer_pokemon(2);
+    assert_eq!(elevage.coefficient_consanguinite(3, 4), 0.25);
+
+    // La filiation des relâchés est sauvegardée avec l'élevage
+    let repertoire = repertoire_temporaire("consanguinite");
+    std::fs::create_dir_all(&repertoire).unwrap();
+    let fichier = repertoire.join("elevage.txt");
+    elevage.sauvegarder(fichier.to_str().unwrap()).unwrap();
+    let mut elevage = Elevage::new();
+    elevage.charger(fichier.to_str().unwrap()).unwrap();
+    std::fs::remove_dir_all(&repertoire).unwrap();
+    assert_eq!(elevage.relaches.len(), 2);
+    assert_eq!(elevage.coefficient_consanguinite(3, 4), 0.25);
+
+    // La politique s'applique toujours aux frère et sœur
+    elevage.regles.politique_consanguinite = PolitiqueConsanguinite::Interdire;
+    assert!(!elevage.tenter_reproduction(3, 4));
+    assert!(elevage.oeufs.is_empty());
+}
@@ -0,0 +1,53 @@
+mod commun;
+
+use commun::{magicarpe, parent};
+use elevage_pokemon_cc1::entrainement::{EV_PAR_SEANCE, SeanceEntrainement};
+use elevage_pokemon_cc1::stats::{EV_MAX_PAR_STAT, EV_MAX_TOTAL, Statistique};
+use elevage_pokemon_cc1::{Elevage, Genre};
+
+#[test]
+fn plafond_des_ev_par_statistique() {
+    let mut pokemon = parent(&magicarpe(), Genre::Male);
+    let attaque = pokemon.stats.attaque;
+
+    assert_eq!(pokemon.gagner_evs(Statistique::Attaque, 200), 200);
+    assert_eq!(pokemon.gagner_evs(Statistique::Attaque, 100), 52);
+    assert_eq!(pokemon.gagner_evs(Statistique::Attaque, 10), 0);
+    assert_eq!(pokemon.evs.attaque, EV_MAX_PAR_STAT);
+    // Les EV gagnés augmentent la statistique
+    assert!(pokemon.stats.attaque > attaque);
+}
+
+#[test]
+fn plafond_des_ev_au_total() {
+    let mut pokemon = parent(&magicarpe(), Genre::Male);
+    assert_eq!(pokemon.gagner_evs(Statistique::Pv, 252), 252);
+    assert_eq!(pokemon.gagner_evs(Statistique::Vitesse, 252), 252);
+    // Il ne reste que 6 EV à répartir
+    assert_eq!(pokemon.gagner_evs(Statistique::Defense, 100), 6);
+    assert_eq!(pokemon.gagner_evs(Statistique::AttaqueSpe, 1), 0);
+    assert_eq!(pokemon.evs.total(), EV_MAX_TOTAL);
+    assert_eq!(pokemon.evs.defense, 6);
+}
+
+#[test]
+fn seances_ciblees_plafonnees() {
+    let mut elevage = Elevage::new();
+    let id = elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+
+    // Chaque séance ciblée rapporte des EV dans sa statistique, jusqu'au plafond
+    for _ in 0..EV_MAX_PAR_STAT / EV_PAR_SEANCE + 5 {
+        elevage.entrainer_tous_pokemon_seance(0, SeanceEntrainement::Ciblee(Statistique::Vitesse));
+    }
+    assert_eq!(
+        elevage.pokemon_par_id(id).unwrap().evs.vitesse,
+        EV_MAX_PAR_STAT
+    );
+
+    // Une séance libre ne rapporte aucun EV
+    elevage.entrainer_tous_pokemon_seance(0, SeanceEntrainement::Libre);
+    assert_eq!(
+        elevage.pokemon_par_id(id).unwrap().evs.total(),
+        EV_MAX_PAR_STAT
+    );
+}
@@ -0,0 +1,76 @@
+use elevage_pokemon_cc1::embarque::DonneesEmbarquees;
+use elevage_pokemon_cc1::especes::{Espece, SourceMemoire, source_depuis_nom};
+use elevage_pokemon_cc1::{Elevage, SpeciesSource, TypePokemon};
+
+#[test]
+fn donnees_embarquees_des_151_especes() {
+    let source = DonneesEmbarquees::new();
+
+    let noms = source.liste_especes().unwrap();
+    assert_eq!(noms.len(), 151);
+    assert_eq!(noms[0], "Bulbasaur");
+    assert_eq!(noms[150], "Mew");
+    assert_eq!(source.identifiants(), (1..=151).collect::<Vec<u32>>());
+    assert_eq!(source.precharger().unwrap(), 151);
+    assert!(source.espece(152).is_err());
+
+    // Les espèces à double type gardent leurs deux types, dans l'ordre
+    assert_eq!(
+        source.espece(1).unwrap().types,
+        vec![TypePokemon::Plante, TypePokemon::Poison]
+    );
+    assert_eq!(
+        source.espece(6).unwrap().types,
+        vec![TypePokemon::Feu, TypePokemon::Vol]
+    );
+    assert_eq!(
+        source.espece(81).unwrap().types,
+        vec![TypePokemon::Electrik, TypePokemon::Acier]
+    );
+
+    let pikachu = source.espece(25).unwrap();
+    assert_eq!(pikachu.nom, "Pikachu");
+    assert_eq!(pikachu.types, vec![TypePokemon::Electrik]);
+    assert_eq!(pikachu.stats_de_base.pv, 35);
+    assert_eq!(pikachu.stats_de_base.vitesse, 90);
+
+    // Toutes les espèces ont au moins un type et des statistiques de base
+    for id in 1..=151 {
+        let espece = source.espece(id).unwrap();
+        assert_eq!(espece.id, id);
+        assert!((1..=2).contains(&espece.types.len()), "{}", espece.nom);
+        assert!(espece.stats_de_base.total() > 0, "{}", espece.nom);
+    }
+}
+
+#[test]
+fn source_memoire() {
+    let espece = |id: u32, nom: &str| Espece {
+        id,
+        nom: nom.to_string(),
+        types: vec![TypePokemon::Normal],
+        ..Espece::default()
+    };
+    let mut source = SourceMemoire::new(vec![espece(52, "Meowth")]);
+    source.ajouter(espece(16, "Pidgey"));
+
+    assert_eq!(source.liste_especes().unwrap(), vec!["Meowth", "Pidgey"]);
+    assert_eq!(source.identifiants(), vec![52, 16]);
+    assert_eq!(source.espece(16).unwrap().nom, "Pidgey");
+    assert!(source.espece(1).is_err());
+
+    // Un élevage ne tire ses Pokémon aléatoires que parmi les espèces de sa source
+    let mut elevage = Elevage::avec_source(Box::new(source));
+    for _ in 0..10 {
+        let id = elevage.ajouter_aleatoire(None).unwrap();
+        let pokemon = elevage.pokemon_par_id(id).unwrap();
+        assert!(matches!(pokemon.espece_id, Some(52) | Some(16)));
+    }
+}
+
+#[test]
+fn source_choisie_par_son_nom() {
+    assert!(source_depuis_nom("embarque").is_some());
+    assert!(source_depuis_nom("Hors-Ligne").is_some());
+    assert!(source_depuis_nom("inconnue").is_none());
+}
@@ -0,0 +1,62 @@
+mod commun;
+
+use commun::repertoire_temporaire;
+use elevage_pokemon_cc1::embarque::DonneesEmbarquees;
+use elevage_pokemon_cc1::{Elevage, Pokemon};
+
+// Élevage contenant un Bulbizarre qui vient d'atteindre le niveau 16 (évolution en attente)
+fn bulbizarre_pret_a_evoluer() -> (Elevage, u32) {
+    let mut elevage = Elevage::new();
+    let mut bulbizarre = Pokemon::from_api(&DonneesEmbarquees::new(), 1).unwrap();
+    bulbizarre.fixer_niveau(15);
+    let id = elevage.ajouter_pokemon(bulbizarre);
+    gagner_un_niveau(&mut elevage, id);
+    (elevage, id)
+}
+
+fn gagner_un_niveau(elevage: &mut Elevage, id: u32) {
+    let pokemon = elevage.pokemon_par_id_mut(id).unwrap();
+    let manquant = pokemon
+        .croissance
+        .experience_pour_niveau(pokemon.niveau + 1)
+        - pokemon.experience;
+    pokemon.gagner_xp(manquant);
+}
+
+#[test]
+fn annuler_une_evolution() {
+    let (mut elevage, id) = bulbizarre_pret_a_evoluer();
+    assert_eq!(elevage.pokemon_par_id(id).unwrap().niveau, 16);
+    assert_eq!(elevage.evolutions_en_attente().len(), 1);
+
+    elevage.annuler_evolution(id);
+    let bulbizarre = elevage.pokemon_par_id(id).unwrap();
+    assert_eq!(bulbizarre.evolution_en_attente, None);
+    assert_eq!(bulbizarre.nom_espece, "Bulbasaur");
+    assert!(elevage.faire_evoluer(id).is_err());
+
+    // L'évolution est reproposée au niveau suivant
+    gagner_un_niveau(&mut elevage, id);
+    assert_eq!(elevage.evolutions_en_attente()[0].1.nom_espece, "Ivysaur");
+    elevage.faire_evoluer(id).unwrap();
+    assert_eq!(elevage.pokemon_par_id(id).unwrap().nom_espece, "Ivysaur");
+}
+
+#[test]
+fn evolution_en_attente_sauvegardee() {
+    let (elevage, id) = bulbizarre_pret_a_evoluer();
+    let repertoire = repertoire_temporaire("evolution");
+    std::fs::create_dir_all(&repertoire).unwrap();
+    let fichier = repertoire.join("elevage.txt");
+    elevage.sauvegarder(fichier.to_str().unwrap()).unwrap();
+
+    let mut charge = Elevage::new();
+    charge.charger(fichier.to_str().unwrap()).unwrap();
+    std::fs::remove_dir_all(&repertoire).unwrap();
+    assert_eq!(
+        charge.pokemon_par_id(id).unwrap().evolution_en_attente,
+        elevage.pokemon_par_id(id).unwrap().evolution_en_attente
+    );
+    charge.faire_evoluer(id).unwrap();
+    assert_eq!(charge.pokemon_par_id(id).unwrap().nom_espece, "Ivysaur");
+}
@@ -0,0 +1,98 @@
+mod commun;
+
+use commun::{magicarpe, parent, petit, repertoire_temporaire};
+use elevage_pokemon_cc1::{Elevage, Genre};
+
+// Une mère au surnom à échapper, terminé par une barre oblique inverse (1), un père relâché (2)
+// et deux petits (3 et 4), dont l'un d'un père jamais enregistré (9)
+fn elevage_exporte() -> Elevage {
+    let mut elevage = Elevage::new();
+    let mut mere = parent(&magicarpe(), Genre::Femelle);
+    mere.nom = "Bulle \"la grande\" #1\\".to_string();
+    elevage.ajouter_pokemon(mere);
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    elevage.ajouter_pokemon(petit(1, 2, Genre::Male));
+    elevage.ajouter_pokemon(petit(1, 9, Genre::Male));
+    elevage.retirer_pokemon(2);
+    elevage
+}
+
+#[test]
+fn pedigree_dot() {
+    assert_eq!(
+        elevage_exporte().pedigree_dot(),
+        "digraph pedigree {
+    node [shape=box];
+    p1 [label=\"#1 Bulle \\\"la grande\\\" #1\\\\\\nEau\\nNiveau 10\\nFemelle\"];
+    p3 [label=\"#3 Magikarp\\nEau\\nNiveau 10\\nMâle\"];
+    p4 [label=\"#4 Magikarp\\nEau\\nNiveau 10\\nMâle\"];
+    p2 [label=\"#2\\n(absent)\", style=dashed];
+    p9 [label=\"#9\\n(absent)\", style=dashed];
+    p1 -> p3 [label=\"mère\"];
+    p2 -> p3 [label=\"père\"];
+    p1 -> p4 [label=\"mère\"];
+    p9 -> p4 [label=\"père\"];
+}
+"
+    );
+}
+
+#[test]
+fn pedigree_mermaid() {
+    assert_eq!(
+        elevage_exporte().pedigree_mermaid(),
+        "graph TD
+    p1[\"#35;1 Bulle #quot;la grande#quot; #35;1\\<br/>Eau<br/>Niveau 10<br/>Femelle\"]
+    p3[\"#35;3 Magikarp<br/>Eau<br/>Niveau 10<br/>Mâle\"]
+    p4[\"#35;4 Magikarp<br/>Eau<br/>Niveau 10<br/>Mâle\"]
+    p2[\"#35;2<br/>(absent)\"]
+    p9[\"#35;9<br/>(absent)\"]
+    p1 -->|mère| p3
+    p2 -->|père| p3
+    p1 -->|mère| p4
+    p9 -->|père| p4
+"
+    );
+}
+
+#[test]
+fn ancetres_relaches_relies_a_leurs_parents() {
+    // 1 x 2 -> 3, puis 3 x 4 -> 5; 3 est relâché
+    let mut elevage = Elevage::new();
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Femelle));
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    elevage.ajouter_pokemon(petit(1, 2, Genre::Femelle));
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    elevage.ajouter_pokemon(petit(3, 4, Genre::Male));
+    elevage.retirer_pokemon(3);
+
+    let dot = elevage.pedigree_dot();
+    assert!(dot.contains("    p3 [label=\"#3\\n(absent)\", style=dashed];\n"));
+    assert!(dot.contains("    p1 -> p3 [label=\"mère\"];\n"));
+    assert!(dot.contains("    p2 -> p3 [label=\"père\"];\n"));
+    assert!(dot.contains("    p3 -> p5 [label=\"mère\"];\n"));
+    assert!(elevage.pedigree_mermaid().contains("    p1 -->|mère| p3\n"));
+
+    // Sans Pokémon, les graphes sont vides
+    let vide = Elevage::new();
+    assert_eq!(
+        vide.pedigree_dot(),
+        "digraph pedigree {\n    node [shape=box];\n}\n"
+    );
+    assert_eq!(vide.pedigree_mermaid(), "graph TD\n");
+}
+
+#[test]
+fn exporter_pedigree() {
+    let repertoire = repertoire_temporaire("export");
+    std::fs::create_dir_all(&repertoire).unwrap();
+    let base = repertoire.join("pedigree");
+    let elevage = elevage_exporte();
+    elevage.exporter_pedigree(base.to_str().unwrap()).unwrap();
+
+    let dot = std::fs::read_to_string(repertoire.join("pedigree.dot")).unwrap();
+    let mermaid = std::fs::read_to_string(repertoire.join("pedigree.mmd")).unwrap();
+    assert_eq!(dot, elevage.pedigree_dot());
+    assert_eq!(mermaid, elevage.pedigree_mermaid());
+    std::fs::remove_dir_all(&repertoire).unwrap();
+}
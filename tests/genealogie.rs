@@ -0,0 +1,76 @@
+mod commun;
+
+use commun::{magicarpe, parent, petit};
+use elevage_pokemon_cc1::{Elevage, Genre, Pokemon};
+
+// Trois générations:
+//   1 (mère) x 2 (père) -> 3 et 4
+//   1 (mère) x 5 (père) -> 7
+//   3 (mère) x 5 (père) -> 6
+fn lignee() -> Elevage {
+    let mut elevage = Elevage::new();
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Femelle));
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    elevage.ajouter_pokemon(petit(1, 2, Genre::Femelle));
+    elevage.ajouter_pokemon(petit(1, 2, Genre::Male));
+    elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    let mut arriere_petit = petit(3, 5, Genre::Male);
+    arriere_petit.generation = 2;
+    elevage.ajouter_pokemon(arriere_petit);
+    elevage.ajouter_pokemon(petit(1, 5, Genre::Femelle));
+    elevage
+}
+
+fn ids(pokemons: Vec<&Pokemon>) -> Vec<u32> {
+    pokemons.iter().map(|p| p.id).collect()
+}
+
+#[test]
+fn parents_et_enfants() {
+    let elevage = lignee();
+    assert_eq!(ids(elevage.parents(6)), vec![3, 5]);
+    assert_eq!(ids(elevage.parents(1)), Vec::<u32>::new());
+    assert_eq!(ids(elevage.enfants(1)), vec![3, 4, 7]);
+    assert_eq!(ids(elevage.enfants(5)), vec![6, 7]);
+    assert_eq!(ids(elevage.enfants(42)), Vec::<u32>::new());
+}
+
+#[test]
+fn ancetres() {
+    let elevage = lignee();
+    // Des parents vers les plus anciens
+    assert_eq!(ids(elevage.ancetres(6)), vec![3, 5, 1, 2]);
+    assert_eq!(ids(elevage.ancetres(7)), vec![1, 5]);
+    assert!(elevage.ancetres(1).is_empty());
+    assert!(elevage.ancetres(42).is_empty());
+}
+
+#[test]
+fn descendants() {
+    let elevage = lignee();
+    // Des enfants vers les plus jeunes, sans doublon (6 descend de 1 par 3 uniquement)
+    assert_eq!(ids(elevage.descendants(1)), vec![3, 4, 7, 6]);
+    assert_eq!(ids(elevage.descendants(2)), vec![3, 4, 6]);
+    assert!(elevage.descendants(6).is_empty());
+}
+
+#[test]
+fn freres_et_soeurs() {
+    let elevage = lignee();
+    // Frères et sœurs et demi-frères, par la mère ou par le père
+    assert_eq!(ids(elevage.freres_et_soeurs(3)), vec![4, 7]);
+    assert_eq!(ids(elevage.freres_et_soeurs(7)), vec![3, 4, 6]);
+    // Les fondateurs n'ont ni frère ni sœur
+    assert!(elevage.freres_et_soeurs(1).is_empty());
+    assert!(elevage.freres_et_soeurs(42).is_empty());
+}
+
+#[test]
+fn lignee_suivie_a_travers_les_relaches() {
+    let mut elevage = lignee();
+    elevage.retirer_pokemon(3);
+
+    assert_eq!(ids(elevage.ancetres(6)), vec![5, 1, 2]);
+    assert_eq!(ids(elevage.descendants(1)), vec![4, 7, 6]);
+    assert_eq!(ids(elevage.parents(6)), vec![5]);
+}
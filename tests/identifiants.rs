@@ -0,0 +1,81 @@
+mod commun;
+
+use commun::{magicarpe, parent, repertoire_temporaire};
+use elevage_pokemon_cc1::{Elevage, Genre};
+
+// Élevage de trois Magicarpe de niveaux différents (identifiants 1, 2 et 3)
+fn elevage_de_trois() -> Elevage {
+    let mut elevage = Elevage::new();
+    for niveau in [5, 30, 15] {
+        let mut pokemon = parent(&magicarpe(), Genre::Male);
+        pokemon.fixer_niveau(niveau);
+        elevage.ajouter_pokemon(pokemon);
+    }
+    elevage
+}
+
+fn identifiants(elevage: &Elevage) -> Vec<(u32, u32)> {
+    let mut ids: Vec<(u32, u32)> = elevage.pokemon.iter().map(|p| (p.id, p.niveau)).collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn identifiants_stables_apres_tri_et_retrait() {
+    let mut elevage = elevage_de_trois();
+    assert_eq!(identifiants(&elevage), vec![(1, 5), (2, 30), (3, 15)]);
+
+    elevage.trier_par_niveau();
+    assert_eq!(
+        elevage.pokemon.iter().map(|p| p.id).collect::<Vec<_>>(),
+        vec![2, 3, 1]
+    );
+    assert_eq!(identifiants(&elevage), vec![(1, 5), (2, 30), (3, 15)]);
+
+    // Un retrait ne décale pas les autres identifiants, et le sien n'est pas réutilisé
+    assert_eq!(elevage.retirer_pokemon(3).unwrap().niveau, 15);
+    assert!(elevage.pokemon_par_id(3).is_none());
+    assert_eq!(elevage.pokemon_par_id(2).unwrap().niveau, 30);
+    let id = elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Femelle));
+    assert_eq!(id, 4);
+}
+
+#[test]
+fn identifiants_stables_apres_sauvegarde() {
+    let repertoire = repertoire_temporaire("identifiants");
+    std::fs::create_dir_all(&repertoire).unwrap();
+    let fichier = repertoire.join("elevage.txt");
+    let fichier = fichier.to_str().unwrap();
+
+    // Le Pokémon au plus grand identifiant est relâché avant la sauvegarde
+    let mut elevage = elevage_de_trois();
+    elevage.trier_par_niveau();
+    elevage.retirer_pokemon(3);
+    elevage.sauvegarder(fichier).unwrap();
+
+    let mut charge = Elevage::new();
+    charge.charger(fichier).unwrap();
+    assert_eq!(identifiants(&charge), vec![(1, 5), (2, 30)]);
+    assert_eq!(charge.prochain_id(), 4);
+    assert_eq!(charge.ajouter_pokemon(parent(&magicarpe(), Genre::Male)), 4);
+
+    // Sans compteur enregistré, les identifiants des parents ne sont pas réattribués
+    let mut orphelin = parent(&magicarpe(), Genre::Male);
+    orphelin.mere_id = Some(7);
+    orphelin.pere_id = Some(9);
+    let mut elevage = Elevage::new();
+    elevage.ajouter_pokemon(orphelin);
+    elevage.sauvegarder(fichier).unwrap();
+    let contenu = std::fs::read_to_string(fichier).unwrap();
+    let sans_compteur: Vec<&str> = contenu
+        .lines()
+        .filter(|ligne| !ligne.starts_with("@prochain_id|"))
+        .collect();
+    std::fs::write(fichier, sans_compteur.join("\n")).unwrap();
+
+    let mut charge = Elevage::new();
+    charge.charger(fichier).unwrap();
+    assert_eq!(charge.prochain_id(), 10);
+
+    std::fs::remove_dir_all(&repertoire).unwrap();
+}
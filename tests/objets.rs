@@ -0,0 +1,53 @@
+mod commun;
+
+use commun::{magicarpe, parent};
+use elevage_pokemon_cc1::especes::SourceMemoire;
+use elevage_pokemon_cc1::nature::Nature;
+use elevage_pokemon_cc1::objet::Objet;
+use elevage_pokemon_cc1::stats::Statistique;
+use elevage_pokemon_cc1::{Elevage, Genre};
+
+#[test]
+fn objets_de_reproduction() {
+    let mut elevage = Elevage::avec_source(Box::new(SourceMemoire::new(vec![magicarpe()])));
+    elevage.regles.ivs_herites = 0;
+    let mut ids = Vec::new();
+    for (genre, iv) in [(Genre::Femelle, 31), (Genre::Male, 30)] {
+        let mut parent = parent(&magicarpe(), genre);
+        parent.nature = Nature::Timide;
+        for stat in Statistique::TOUTES {
+            parent.ivs.definir(stat, iv);
+        }
+        ids.push(elevage.ajouter_pokemon(parent));
+    }
+    elevage.pokemon_par_id_mut(ids[0]).unwrap().nature = Nature::Modeste;
+
+    // La Pierre Stase transmet la nature et l'objet Pouvoir impose l'IV du parent qui le tient
+    assert_eq!(
+        elevage
+            .donner_objet(ids[0], Some(Objet::PierreStase))
+            .unwrap(),
+        None
+    );
+    elevage
+        .donner_objet(ids[1], Some(Objet::Pouvoir(Statistique::Vitesse)))
+        .unwrap();
+    assert!(elevage.tenter_reproduction(ids[0], ids[1]));
+    let bebe = &elevage.oeufs[0].pokemon;
+    assert_eq!(bebe.nature, Nature::Modeste);
+    assert_eq!(bebe.ivs.valeur(Statistique::Vitesse), 30);
+
+    // Le Nœud Destin transmet 5 IV au lieu du nombre prévu par les règles
+    elevage.donner_objet(ids[1], None).unwrap();
+    elevage
+        .donner_objet(ids[0], Some(Objet::NoeudDestin))
+        .unwrap();
+    assert!(elevage.tenter_reproduction(ids[0], ids[1]));
+    let bebe = &elevage.oeufs[1].pokemon;
+    let herites = Statistique::TOUTES
+        .iter()
+        .filter(|stat| bebe.ivs.valeur(**stat) >= 30)
+        .count();
+    assert!(herites >= 5);
+    assert!(elevage.donner_objet(99, None).is_err());
+}
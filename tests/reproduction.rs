@@ -0,0 +1,272 @@
+mod commun;
+
+use commun::{ServeurStub, magicarpe, parent, routes_bulbasaur};
+use elevage_pokemon_cc1::api::PokeApi;
+use elevage_pokemon_cc1::embarque::DonneesEmbarquees;
+use elevage_pokemon_cc1::especes::{Espece, SourceMemoire};
+use elevage_pokemon_cc1::groupe_oeuf::GroupeOeuf;
+use elevage_pokemon_cc1::oeuf::PAS_PAR_CYCLE;
+use elevage_pokemon_cc1::pokemon::RepartitionGenres;
+use elevage_pokemon_cc1::regles::ReglesElevage;
+use elevage_pokemon_cc1::reproduction::{heriter_ivs, reproduction_avec_regles};
+use elevage_pokemon_cc1::stats::{Statistique, Stats};
+use elevage_pokemon_cc1::talent::Talent;
+use elevage_pokemon_cc1::{Elevage, Genre, Pokemon, SpeciesSource, TypePokemon};
+
+#[test]
+fn compatibilite_par_groupe_oeuf() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let mut bulbasaur = Pokemon::from_api(&source, 1).unwrap();
+    assert_eq!(bulbasaur.repartition_genres, RepartitionGenres::Femelles(1));
+    bulbasaur.fixer_niveau(10);
+    bulbasaur.genre = Genre::Femelle;
+    assert_eq!(
+        bulbasaur.groupes_oeuf,
+        vec![GroupeOeuf::Monstre, GroupeOeuf::Plante]
+    );
+
+    let espece = |nom: &str, types, groupes_oeuf| Espece {
+        nom: nom.to_string(),
+        types,
+        groupes_oeuf,
+        ..Espece::default()
+    };
+    let mut charmander = Pokemon::depuis_espece(&espece(
+        "Charmander",
+        vec![TypePokemon::Feu],
+        vec![GroupeOeuf::Monstre, GroupeOeuf::Dragon],
+    ));
+    charmander.fixer_niveau(10);
+    charmander.genre = Genre::Male;
+    let mut ditto = Pokemon::depuis_espece(&espece(
+        "Ditto",
+        vec![TypePokemon::Normal],
+        vec![GroupeOeuf::Metamorph],
+    ));
+    ditto.fixer_niveau(10);
+    ditto.genre = Genre::Femelle;
+    let mut magneti = Pokemon::depuis_espece(&Espece {
+        repartition_genres: RepartitionGenres::Asexuee,
+        ..espece(
+            "Magnemite",
+            vec![TypePokemon::Electrik],
+            vec![GroupeOeuf::Mineral],
+        )
+    });
+    magneti.fixer_niveau(10);
+    let mut mewtwo = Pokemon::depuis_espece(&espece(
+        "Mewtwo",
+        vec![TypePokemon::Psy],
+        vec![GroupeOeuf::Inconnu],
+    ));
+    mewtwo.fixer_niveau(70);
+
+    // Groupe Monstre commun malgré des types différents
+    assert!(bulbasaur.peut_se_reproduire_avec(&charmander));
+    // Métamorph est un partenaire universel, quel que soit le genre
+    assert!(bulbasaur.peut_se_reproduire_avec(&ditto));
+    assert!(!ditto.peut_se_reproduire_avec(&ditto.clone()));
+    assert!(!mewtwo.peut_se_reproduire_avec(&ditto));
+
+    // Les Pokémon asexués ne se reproduisent qu'avec Métamorph
+    assert_eq!(magneti.genre, Genre::Inconnu);
+    assert!(magneti.peut_se_reproduire_avec(&ditto));
+    assert!(!magneti.peut_se_reproduire_avec(&magneti.clone()));
+
+    // Le niveau minimum est une règle de l'élevage
+    let regles = ReglesElevage {
+        niveau_minimum: 20,
+        ..ReglesElevage::default()
+    };
+    assert!(!bulbasaur.peut_se_reproduire_avec_regles(&charmander, &regles));
+}
+
+#[test]
+fn la_reproduction_pond_un_oeuf() {
+    let magicarpe = Espece {
+        cycles_eclosion: Some(5),
+        ..magicarpe()
+    };
+    let mut elevage = Elevage::avec_source(Box::new(SourceMemoire::new(vec![magicarpe.clone()])));
+    let ids: Vec<u32> = [Genre::Femelle, Genre::Male]
+        .into_iter()
+        .map(|genre| elevage.ajouter_pokemon(parent(&magicarpe, genre)))
+        .collect();
+
+    assert!(elevage.tenter_reproduction(ids[0], ids[1]));
+    assert_eq!(elevage.pokemon.len(), 2);
+    assert_eq!(elevage.oeufs[0].pas_restants, 5 * PAS_PAR_CYCLE);
+
+    assert!(elevage.faire_avancer_oeufs(4 * PAS_PAR_CYCLE).is_empty());
+    let eclos = elevage.faire_avancer_oeufs(PAS_PAR_CYCLE);
+    assert_eq!(eclos.len(), 1);
+    assert!(elevage.oeufs.is_empty());
+    assert_eq!(
+        elevage.pokemon_par_id(eclos[0]).unwrap().mere_id,
+        Some(ids[0])
+    );
+}
+
+#[test]
+fn methode_masuda_avec_des_origines_differentes() {
+    let mere = parent(&magicarpe(), Genre::Femelle);
+    let mut pere = parent(&magicarpe(), Genre::Male);
+    pere.origine = "Japon".to_string();
+
+    // Avec un multiplicateur égal aux chances, le petit est forcément chromatique
+    let regles = ReglesElevage {
+        chances_chromatique: 8,
+        multiplicateur_masuda: 8,
+        ..ReglesElevage::default()
+    };
+    let bebe = reproduction_avec_regles(&mere, &pere, &regles).unwrap();
+
+    assert!(bebe.chromatique);
+}
+
+#[test]
+fn chromatique_tire_selon_les_regles_de_l_elevage() {
+    let source = DonneesEmbarquees::new();
+    // Les Pokémon créés hors de l'élevage ne sont jamais chromatiques
+    for id in 1..=151 {
+        assert!(!Pokemon::from_api(&source, id).unwrap().chromatique);
+    }
+
+    // Avec une chance sur 1, tout Pokémon qui arrive dans l'élevage est chromatique
+    let mut elevage = Elevage::new();
+    elevage.regles.chances_chromatique = 1;
+    let aleatoire = elevage.ajouter_aleatoire(None).unwrap();
+    let ajoute = elevage.ajouter_pokemon(Pokemon::from_api(&source, 25).unwrap());
+    assert!(elevage.pokemon_par_id(aleatoire).unwrap().chromatique);
+    assert!(elevage.pokemon_par_id(ajoute).unwrap().chromatique);
+
+    // Avec des chances infimes, aucun ne l'est (un seul tirage, celui des règles)
+    elevage.regles.chances_chromatique = u32::MAX;
+    for _ in 0..50 {
+        let id = elevage.ajouter_aleatoire(None).unwrap();
+        assert!(!elevage.pokemon_par_id(id).unwrap().chromatique);
+    }
+}
+
+#[test]
+fn attaques_oeuf_heritees_du_pere() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    // Les attaques Œuf sont lues dans Or/Argent, Rouge/Bleu n'en ayant pas
+    let espece = source.espece(1).unwrap();
+    assert_eq!(espece.attaques_oeuf, vec!["Razor Wind"]);
+
+    let mere = parent(&espece, Genre::Femelle);
+    let mut pere = parent(&espece, Genre::Male);
+    pere.attaques.push("Razor Wind".to_string());
+
+    let bebe = reproduction_avec_regles(&mere, &pere, &ReglesElevage::default()).unwrap();
+    assert_eq!(bebe.attaques, vec!["Tackle", "Growl", "Razor Wind"]);
+    assert!(
+        bebe.historique
+            .iter()
+            .any(|entree| entree.contains("Razor Wind"))
+    );
+
+    // Une attaque Œuf que le père ne connaît pas n'est pas transmise
+    pere.attaques.pop();
+    let bebe = reproduction_avec_regles(&mere, &pere, &ReglesElevage::default()).unwrap();
+    assert_eq!(bebe.attaques, vec!["Tackle", "Growl"]);
+}
+
+#[test]
+fn talent_cache_transmis_par_la_mere() {
+    let serveur = ServeurStub::demarrer(routes_bulbasaur());
+    let source = PokeApi::avec_config(serveur.config());
+
+    let espece = source.espece(1).unwrap();
+    assert_eq!(
+        espece.talents,
+        vec![
+            Talent {
+                nom: "Overgrow".to_string(),
+                cache: false
+            },
+            Talent {
+                nom: "Chlorophyll".to_string(),
+                cache: true
+            }
+        ]
+    );
+
+    // Un Pokémon reçoit l'un des talents ordinaires de son espèce
+    let mut mere = parent(&espece, Genre::Femelle);
+    assert_eq!(mere.talent, Some(espece.talents[0].clone()));
+    let mut pere = parent(&espece, Genre::Male);
+
+    let mut regles = ReglesElevage {
+        chances_talent_cache: 100,
+        ..ReglesElevage::default()
+    };
+    mere.talent = Some(espece.talents[1].clone());
+    let bebe = reproduction_avec_regles(&mere, &pere, &regles).unwrap();
+    assert_eq!(bebe.talent, Some(espece.talents[1].clone()));
+
+    regles.chances_talent_cache = 0;
+    let bebe = reproduction_avec_regles(&mere, &pere, &regles).unwrap();
+    assert_eq!(bebe.talent, Some(espece.talents[0].clone()));
+
+    // Le talent caché du père n'est pas transmis
+    pere.talent = Some(espece.talents[1].clone());
+    mere.talent = Some(espece.talents[0].clone());
+    regles.chances_talent_cache = 100;
+    let bebe = reproduction_avec_regles(&mere, &pere, &regles).unwrap();
+    assert_eq!(bebe.talent, Some(espece.talents[0].clone()));
+}
+
+#[test]
+fn ivs_herites_selon_les_regles() {
+    // Les IV de la mère valent 31 et ceux du père 30: tout autre IV a été tiré au hasard
+    let mut mere = parent(&magicarpe(), Genre::Femelle);
+    mere.ivs = Stats::uniformes(31);
+    let mut pere = parent(&magicarpe(), Genre::Male);
+    pere.ivs = Stats::uniformes(30);
+    let herites = |ivs: &Stats| {
+        Statistique::TOUTES
+            .iter()
+            .filter(|stat| ivs.valeur(**stat) >= 30)
+            .count()
+    };
+
+    for nombre in 0..=6 {
+        let regles = ReglesElevage {
+            ivs_herites: nombre,
+            ..ReglesElevage::default()
+        };
+        let mut tous_herites = true;
+        for _ in 0..50 {
+            let bebe = reproduction_avec_regles(&mere, &pere, &regles).unwrap();
+            assert!(herites(&bebe.ivs) >= nombre, "{} IV transmis", nombre);
+            tous_herites &= herites(&bebe.ivs) == 6;
+        }
+        // Au-delà des IV transmis, les IV sont tirés au hasard
+        assert_eq!(tous_herites, nombre == 6, "{} IV transmis", nombre);
+    }
+
+    // Chaque IV transmis vient de l'un des deux parents
+    let ivs = heriter_ivs(&mere, &pere, 6);
+    for stat in Statistique::TOUTES {
+        assert!(matches!(ivs.valeur(stat), 30 | 31));
+    }
+}
+
+#[test]
+fn donnees_de_talents_manquantes_signalees() {
+    let source = DonneesEmbarquees::new();
+    // Les données embarquées n'ont pas les talents de Miaouss
+    let miaouss = Pokemon::from_api(&source, 52).unwrap();
+    assert!(miaouss.talent.is_none());
+    assert!(miaouss.donnees_manquantes().contains(&"talents"));
+
+    let bulbizarre = Pokemon::from_api(&source, 1).unwrap();
+    assert!(bulbizarre.talent.is_some());
+    assert!(!bulbizarre.donnees_manquantes().contains(&"talents"));
+}
@@ -0,0 +1,144 @@
+mod commun;
+
+use commun::{magicarpe, parent, repertoire_temporaire};
+use elevage_pokemon_cc1::embarque::DonneesEmbarquees;
+use elevage_pokemon_cc1::nature::Nature;
+use elevage_pokemon_cc1::objet::Objet;
+use elevage_pokemon_cc1::oeuf::Oeuf;
+use elevage_pokemon_cc1::stats::{Statistique, Stats};
+use elevage_pokemon_cc1::talent::talent_cache;
+use elevage_pokemon_cc1::{Elevage, Genre, Pokemon};
+
+// Sauvegarder puis recharger un élevage dans un nouvel élevage
+fn recharger(elevage: &Elevage, nom: &str) -> Elevage {
+    let repertoire = repertoire_temporaire(nom);
+    std::fs::create_dir_all(&repertoire).unwrap();
+    let fichier = repertoire.join("elevage.txt");
+    elevage.sauvegarder(fichier.to_str().unwrap()).unwrap();
+    let mut charge = Elevage::new();
+    charge.charger(fichier.to_str().unwrap()).unwrap();
+    std::fs::remove_dir_all(&repertoire).unwrap();
+    charge
+}
+
+#[test]
+fn textes_libres_echappes() {
+    let mut elevage = Elevage::new();
+    let mut pokemon = parent(&magicarpe(), Genre::Femelle);
+    pokemon.nom = "Bulle; la | grande \\n\\".to_string();
+    pokemon.origine = "Kanto | Johto\nHoenn".to_string();
+    pokemon.historique = vec!["Né; à la pension".to_string(), "a | b".to_string()];
+    let id = elevage.ajouter_pokemon(pokemon.clone());
+
+    let charge = recharger(&elevage, "sauvegarde-echappement");
+    let relu = charge.pokemon_par_id(id).unwrap();
+    assert_eq!(relu.nom, pokemon.nom);
+    assert_eq!(relu.origine, pokemon.origine);
+    assert_eq!(relu.historique, pokemon.historique);
+    // Les colonnes suivantes ne sont pas décalées
+    assert_eq!(relu.ivs, pokemon.ivs);
+    assert_eq!(relu.nature, pokemon.nature);
+    assert_eq!(relu.attaques, pokemon.attaques);
+}
+
+// Tous les champs sauvegardés doivent être relus à l'identique
+fn assert_identiques(relu: &Pokemon, original: &Pokemon) {
+    assert_eq!(relu.id, original.id);
+    assert_eq!(relu.nom, original.nom);
+    assert_eq!(relu.espece_id, original.espece_id);
+    assert_eq!(relu.nom_espece, original.nom_espece);
+    assert_eq!(relu.niveau, original.niveau);
+    assert_eq!(relu.type_pokemon, original.type_pokemon);
+    assert_eq!(relu.type_secondaire, original.type_secondaire);
+    assert_eq!(relu.experience, original.experience);
+    assert_eq!(relu.croissance, original.croissance);
+    assert_eq!(relu.genre, original.genre);
+    assert_eq!(relu.repartition_genres, original.repartition_genres);
+    assert_eq!(relu.mere_id, original.mere_id);
+    assert_eq!(relu.pere_id, original.pere_id);
+    assert_eq!(relu.generation, original.generation);
+    assert_eq!(relu.malus_consanguinite, original.malus_consanguinite);
+    assert_eq!(relu.ivs, original.ivs);
+    assert_eq!(relu.stats_de_base, original.stats_de_base);
+    assert_eq!(relu.evs, original.evs);
+    assert_eq!(relu.stats, original.stats);
+    assert_eq!(relu.nature, original.nature);
+    assert_eq!(relu.objet, original.objet);
+    assert_eq!(relu.evolutions, original.evolutions);
+    assert_eq!(relu.evolution_en_attente, original.evolution_en_attente);
+    assert_eq!(relu.historique, original.historique);
+    assert_eq!(relu.groupes_oeuf, original.groupes_oeuf);
+    assert_eq!(relu.chromatique, original.chromatique);
+    assert_eq!(relu.origine, original.origine);
+    assert_eq!(relu.attaques, original.attaques);
+    assert_eq!(relu.apprentissage, original.apprentissage);
+    assert_eq!(relu.attaques_en_attente, original.attaques_en_attente);
+    assert_eq!(relu.attaques_oeuf, original.attaques_oeuf);
+    assert_eq!(relu.talent, original.talent);
+    assert_eq!(relu.talents, original.talents);
+}
+
+#[test]
+fn sauvegarde_complete() {
+    let source = DonneesEmbarquees::new();
+    let mut elevage = Elevage::new();
+    let mere = elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Femelle));
+    let pere = elevage.ajouter_pokemon(parent(&magicarpe(), Genre::Male));
+    elevage.retirer_pokemon(mere);
+    elevage.retirer_pokemon(pere);
+
+    // Un Bulbizarre entraîné, surnommé avec des séparateurs, né de deux Pokémon relâchés
+    let mut bulbizarre = Pokemon::from_api(&source, 1).unwrap();
+    bulbizarre.nom = "Bulle; la | grande".to_string();
+    bulbizarre.genre = Genre::Femelle;
+    bulbizarre.fixer_niveau(12);
+    bulbizarre.experience += 10;
+    bulbizarre.ivs = Stats::uniformes(31);
+    bulbizarre.nature = Nature::Timide;
+    bulbizarre.gagner_evs(Statistique::Vitesse, 100);
+    bulbizarre.gagner_evs(Statistique::AttaqueSpe, 40);
+    bulbizarre.objet = Some(Objet::Pouvoir(Statistique::Vitesse));
+    bulbizarre.chromatique = true;
+    bulbizarre.origine = "Johto".to_string();
+    bulbizarre.talent = talent_cache(&bulbizarre.talents);
+    bulbizarre.mere_id = Some(mere);
+    bulbizarre.pere_id = Some(pere);
+    bulbizarre.generation = 1;
+    bulbizarre.malus_consanguinite = 0.125;
+    bulbizarre
+        .historique
+        .push("Attaques Œuf héritées de Pépé: Petal Dance".to_string());
+    bulbizarre.recalculer_stats();
+    let id = elevage.ajouter_pokemon(bulbizarre);
+    let bulbizarre = elevage.pokemon_par_id(id).unwrap().clone();
+    assert!(!bulbizarre.attaques.is_empty());
+    assert!(
+        bulbizarre
+            .talent
+            .as_ref()
+            .is_some_and(|talent| talent.cache)
+    );
+
+    // Un Magicarpe créé sans espèce, et un œuf
+    let mut magicarpe = parent(&magicarpe(), Genre::Male);
+    magicarpe.objet = Some(Objet::NoeudDestin);
+    let id_magicarpe = elevage.ajouter_pokemon(magicarpe);
+    let magicarpe = elevage.pokemon_par_id(id_magicarpe).unwrap().clone();
+    let mut bebe = Pokemon::from_api(&source, 4).unwrap();
+    bebe.nom = "Flam|mèche".to_string();
+    bebe.mere_id = Some(id);
+    bebe.pere_id = Some(id_magicarpe);
+    bebe.generation = 2;
+    elevage.oeufs.push(Oeuf::new(bebe.clone(), 20));
+    elevage.oeufs[0].avancer(1000);
+
+    let charge = recharger(&elevage, "sauvegarde-complete");
+    assert_eq!(charge.pokemon.len(), 2);
+    assert_identiques(charge.pokemon_par_id(id).unwrap(), &bulbizarre);
+    assert_identiques(charge.pokemon_par_id(id_magicarpe).unwrap(), &magicarpe);
+    assert_eq!(charge.oeufs.len(), 1);
+    assert_eq!(charge.oeufs[0].pas_restants, 20 * 256 - 1000);
+    assert_identiques(&charge.oeufs[0].pokemon, &bebe);
+    assert_eq!(charge.relaches, elevage.relaches);
+    assert_eq!(charge.prochain_id(), elevage.prochain_id());
+}
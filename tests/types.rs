@@ -0,0 +1,44 @@
+use elevage_pokemon_cc1::api::convert_type_from_api;
+use elevage_pokemon_cc1::efficacite::{efficacite, efficacite_double};
+use elevage_pokemon_cc1::{Genre, Pokemon, TypePokemon};
+
+#[test]
+fn table_des_types() {
+    assert_eq!(convert_type_from_api("steel"), TypePokemon::Acier);
+    assert_eq!(convert_type_from_api("dark"), TypePokemon::Tenebres);
+
+    assert_eq!(efficacite(&TypePokemon::Feu, &TypePokemon::Plante), 2.0);
+    assert_eq!(efficacite(&TypePokemon::Eau, &TypePokemon::Plante), 0.5);
+    assert_eq!(efficacite(&TypePokemon::Sol, &TypePokemon::Vol), 0.0);
+    assert_eq!(efficacite(&TypePokemon::Tenebres, &TypePokemon::Psy), 2.0);
+    assert_eq!(efficacite(&TypePokemon::Acier, &TypePokemon::Fee), 2.0);
+    assert_eq!(efficacite(&TypePokemon::Normal, &TypePokemon::Normal), 1.0);
+
+    // Double type: les multiplicateurs se combinent
+    assert_eq!(
+        efficacite_double(
+            &TypePokemon::Electrik,
+            &TypePokemon::Eau,
+            Some(&TypePokemon::Vol)
+        ),
+        4.0
+    );
+    assert_eq!(
+        efficacite_double(
+            &TypePokemon::Combat,
+            &TypePokemon::Normal,
+            Some(&TypePokemon::Spectre)
+        ),
+        0.0
+    );
+
+    let mut magneti = Pokemon::new(
+        "Magnemite".to_string(),
+        TypePokemon::Electrik,
+        Genre::Inconnu,
+    );
+    magneti.type_secondaire = Some(TypePokemon::Acier);
+    assert_eq!(magneti.efficacite_recue(&TypePokemon::Sol), 4.0);
+    assert_eq!(magneti.efficacite_recue(&TypePokemon::Poison), 0.0);
+    assert_eq!(magneti.efficacite_recue(&TypePokemon::Vol), 0.25);
+}